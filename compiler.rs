@@ -1,79 +1,663 @@
-use crate::lexer::Lexer;
-use crate::parser::Parser;
-use crate::semantic_analyzer::SemanticAnalyzer;
-use crate::error_reporter::ErrorReporter;
-use crate::memory_manager::MemoryManager;
-use crate::codegen::CodeGenerator;
-use crate::linker::Linker;
-use std::fs;
-use std::io;
-use std::io::Write;
-
-pub struct Compiler {
-    error_reporter: ErrorReporter,
-}
-
-impl Compiler {
-    pub fn new() -> Self {
-        Compiler {
-            error_reporter: ErrorReporter::new(),
-        }
-    }
-
-    pub fn compile(&mut self, input_filename: &str, output_filename: &str) -> Result<(), io::Error> {
-        let input_code = fs::read_to_string(input_filename)?;
-        let mut lexer = Lexer::new(input_code);
-        let mut parser = Parser::new(lexer);
-        let ast = parser.parse_program();
-
-        let mut semantic_analyzer = SemanticAnalyzer::new();
-        semantic_analyzer.analyze(&ast);
-        semantic_analyzer.analyze_control_flow(&ast);
-        semantic_analyzer.analyze_functions(&ast);
-        semantic_analyzer.analyze_variables(&ast);
-        semantic_analyzer.analyze_operators(&ast);
-        semantic_analyzer.analyze_io(&ast);
-
-        if self.error_reporter.has_errors() {
-            self.error_reporter.print_errors();
-            return Ok(());
-        }
-
-        let memory_manager = MemoryManager::new(0x1000); // Statik bellek başlangıç adresi
-        // Sembol tablosunu kullanarak statik değişkenler ve handle'lar için bellek/kayıt ayır
-        if let crate::parser::AstNode::Program(statements) = &ast {
-            for statement in statements {
-                match statement {
-                    AstNode::Instruction { opcode, operands } if opcode == "VAR" && operands.len() == 2 => {
-                        if let (Expression::Identifier(name), Expression::Identifier(type_name)) = (&operands[0], &operands[1]) {
-                            let size = semantic_analyzer.resolve_type(type_name).size().unwrap_or(4); // Varsayılan 4
-                            memory_manager.allocate_static(name, size).unwrap();
-                        }
-                    }
-                    AstNode::AllocateMemory { handle, .. } |
-                    AstNode::AcquireResource { handle, .. } |
-                    AstNode::GetTaskId { target: handle } |
-                    AstNode::GetCoreId { target: handle } |
-                    AstNode::GetTotalCores { target: handle } => {
-                        memory_manager.allocate_handle(handle).unwrap();
-                    }
-                    _ => {}
-                }
-            }
-        }
-
-        let code_generator = CodeGenerator::new(semantic_analyzer.symbol_table, memory_manager);
-        let generated_code = code_generator.generate_code(&ast);
-
-        let linker = Linker::new();
-        let linked_code = linker.link(generated_code);
-
-        let mut output_file = fs::File::create(output_filename)?;
-        for line in linked_code {
-            writeln!(output_file, "{}", line)?;
-        }
-
-        println!("Derleme başarılı. Çıktı dosyası: {}", output_filename);
-        Ok(())
-    }
-}
\ No newline at end of file
+use crate::keywords::CaseSensitivity;
+use crate::lexer::{FileId, Lexer};
+use crate::parser::{AstNode, DataKind, Expression, Parser, Section};
+use crate::semantic_analyzer::SemanticAnalyzer;
+use crate::error_reporter::{Diagnostic, ErrorReporter, Lint, LintLevel, MessageFormat, Severity};
+use crate::memory_manager::{MemoryAllocation, MemoryManager, MemorySection};
+use crate::codegen::CodeGenerator;
+use crate::extern_symbols::SymbolBinding;
+use crate::linker::{Linker, ObjectFile};
+use crate::file_provider::{FileProvider, SourceFile};
+use crate::messages::{msg, Language};
+use crate::symbol_table::{SymbolTable, SymbolType};
+use crate::target::Target;
+use std::io;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Stage {
+    Parse,
+    Semantic,
+    Layout, // Statik bellek ve handle yerleşimi
+    Codegen,
+    Link,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StageStatus {
+    Succeeded,
+    Failed,
+    Skipped, // Önceki bir aşama başarısız olduğu için çalıştırılmadı
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Artifacts {
+    pub object: Option<Vec<String>>,  // Bağlanmış kod (.bs64 içeriği)
+    pub listing: Option<Vec<String>>, // Bağlama öncesi üretilen kod, satır numaralı
+    pub map: Option<Vec<String>>,     // Statik bellek ve handle yerleşimi
+}
+
+const STAGES: [Stage; 5] = [Stage::Parse, Stage::Semantic, Stage::Layout, Stage::Codegen, Stage::Link];
+
+// Tek bir kaynak dosyanın derleme çıktısı; alanlar ilgili aşama tamamlandıkça dolar
+#[derive(Debug, Clone)]
+pub struct UnitOutput {
+    pub name: String,
+    pub ast: AstNode,
+    pub symbol_table: Option<SymbolTable>,
+    pub memory: Option<Vec<(String, MemoryAllocation)>>, // Statik ve handle yerleşimi
+    pub object: Option<ObjectFile>, // Bağlama öncesi üretilen kod ve dışa açılan/beklenen semboller
+}
+
+#[derive(Debug, Clone)]
+pub struct CompileResult {
+    pub sources: Vec<SourceFile>, // Derlenen kaynaklar; tanılamaları kaynak satırlarıyla göstermek için
+    pub case_sensitivity: CaseSensitivity, // Kaynakların okunduğu kural; --emit tokens aynı kuralla tarar
+    pub diagnostics: Vec<Diagnostic>,
+    pub artifacts: Artifacts,
+    pub stages: Vec<(Stage, StageStatus)>,
+    pub units: Vec<UnitOutput>,
+}
+
+impl CompileResult {
+    fn new() -> Self {
+        CompileResult {
+            sources: Vec::new(),
+            case_sensitivity: CaseSensitivity::default(),
+            diagnostics: Vec::new(),
+            artifacts: Artifacts::default(),
+            stages: Vec::new(),
+            units: Vec::new(),
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        !self.has_errors() && self.stages.iter().all(|(_, status)| *status != StageStatus::Failed)
+    }
+
+    pub fn source(&self, name: &str) -> Option<&str> {
+        self.sources.iter().find(|source| source.name == name).map(|source| source.text.as_str())
+    }
+
+    // Tanılamaları istenen biçimde yazar; insan okuması için olanlar kaynak
+    // satırlarıyla birlikte ve aralarında boş satır bırakılarak gösterilir,
+    // sonuna da hata kodlarının açıklamasına nasıl ulaşılacağı eklenir
+    pub fn render_diagnostics(&self, format: MessageFormat, color: bool, language: Language) -> String {
+        let separator = if format == MessageFormat::Human { "\n" } else { "" };
+        let mut out = self
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.format(format, diagnostic.file.as_deref().and_then(|file| self.source(file)), color, language))
+            .collect::<Vec<_>>()
+            .join(separator);
+
+        let mut codes: Vec<&str> = self.diagnostics.iter().filter(|d| d.severity == Severity::Error).map(|d| d.code.as_str()).collect();
+        codes.sort();
+        codes.dedup();
+        if format == MessageFormat::Human && !codes.is_empty() {
+            if codes.len() > 1 {
+                out.push_str(&format!("\n{}\n", msg!("ui.explained-codes", codes.join(", ")).text(language)));
+                out.push_str(&format!("{}\n", msg!("ui.explain-any", codes[0]).text(language)));
+            } else {
+                out.push_str(&format!("\n{}\n", msg!("ui.explain-one", codes[0]).text(language)));
+            }
+        }
+        out
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(|d| d.severity == Severity::Error)
+    }
+
+    pub fn stage_status(&self, stage: Stage) -> StageStatus {
+        self.stages
+            .iter()
+            .find(|(s, _)| *s == stage)
+            .map(|(_, status)| *status)
+            .unwrap_or(StageStatus::Skipped)
+    }
+
+    // Aşamanın sonucunu kaydeder; derleme devam edecekse true döner
+    fn record(&mut self, stage: Stage, succeeded: bool, last_stage: Stage) -> bool {
+        let status = if succeeded { StageStatus::Succeeded } else { StageStatus::Failed };
+        self.stages.push((stage, status));
+        if succeeded && stage < last_stage {
+            return true;
+        }
+        for remaining in STAGES.iter().filter(|s| **s > stage) {
+            self.stages.push((*remaining, StageStatus::Skipped));
+        }
+        false
+    }
+}
+
+pub struct Compiler {
+    error_reporter: ErrorReporter,
+    target: Target,
+    case_sensitivity: CaseSensitivity,
+}
+
+impl Default for Compiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Compiler {
+    pub fn new() -> Self {
+        Compiler {
+            error_reporter: ErrorReporter::new(),
+            target: Target::default(),
+            case_sensitivity: CaseSensitivity::default(),
+        }
+    }
+
+    // Hedef makine (register sayısı vb.); varsayılan Sahne64
+    pub fn set_target(&mut self, target: Target) {
+        self.target = target;
+    }
+
+    // Anahtar kelime, komut, flag ve register adlarının büyük/küçük harf kuralı;
+    // varsayılan olarak "jump" ve "JUMP" aynıdır
+    pub fn set_case_sensitivity(&mut self, case_sensitivity: CaseSensitivity) {
+        self.case_sensitivity = case_sensitivity;
+    }
+
+    // Bir uyarı kategorisinin seviyesini belirler (-A/-W/-D)
+    pub fn set_lint_level(&mut self, lint: Lint, level: LintLevel) {
+        self.error_reporter.set_lint_level(lint, level);
+    }
+
+    // Uyarı seviyesindeki tüm tanılamaları hataya dönüştürür (-Werror)
+    pub fn set_warnings_as_errors(&mut self, enabled: bool) {
+        self.error_reporter.set_warnings_as_errors(enabled);
+    }
+
+    // Girdi dosyalarını ayrı ayrı derleyip tek çıktıda bağlar; derleme başarılıysa çıktı dosyasına yazar
+    pub fn compile(&mut self, provider: &mut dyn FileProvider, input_filenames: &[&str], output_filename: &str) -> Result<CompileResult, io::Error> {
+        let sources = read_sources(provider, input_filenames)?;
+        let result = self.compile_sources(&sources);
+
+        if let (true, Some(object)) = (result.is_success(), &result.artifacts.object) {
+            provider.write_output(output_filename, object)?;
+        }
+        Ok(result)
+    }
+
+    // Kod üretmeden yalnızca ayrıştırma ve semantik analiz yapar
+    pub fn check(&mut self, provider: &dyn FileProvider, input_filenames: &[&str]) -> Result<CompileResult, io::Error> {
+        let sources = read_sources(provider, input_filenames)?;
+        Ok(self.run(&sources, Stage::Semantic))
+    }
+
+    pub fn compile_source(&mut self, input_code: String) -> CompileResult {
+        self.compile_sources(&[SourceFile { name: "<girdi>".to_string(), text: input_code }])
+    }
+
+    // Bellekteki adlandırılmış kaynakları dosya sistemine dokunmadan derler
+    pub fn compile_sources(&mut self, sources: &[SourceFile]) -> CompileResult {
+        self.run(sources, Stage::Link)
+    }
+
+    fn run(&mut self, sources: &[SourceFile], last_stage: Stage) -> CompileResult {
+        let mut result = CompileResult::new();
+        result.sources = sources.to_vec();
+        result.case_sensitivity = self.case_sensitivity;
+        self.run_pipeline(sources, &mut result, last_stage);
+        result.diagnostics = self.error_reporter.take_diagnostics();
+        result
+    }
+
+    fn run_pipeline(&mut self, sources: &[SourceFile], result: &mut CompileResult, last_stage: Stage) {
+        for (index, source) in sources.iter().enumerate() {
+            self.error_reporter.set_file(&source.name);
+            let mut lexer = Lexer::new(source.text.clone());
+            lexer.set_file(FileId(index));
+            lexer.set_case_sensitivity(self.case_sensitivity);
+            let mut parser = Parser::new(lexer);
+            let ast = parser.parse_program();
+            self.error_reporter.extend(parser.take_diagnostics());
+            result.units.push(UnitOutput {
+                name: source.name.clone(),
+                ast,
+                symbol_table: None,
+                memory: None,
+                object: None,
+            });
+        }
+        let parse_errors = self.error_reporter.error_count();
+        if parse_errors == 0 {
+            if !result.record(Stage::Parse, true, last_stage) {
+                return;
+            }
+        } else {
+            // Kurtarılan deyimler yine de analiz edilir; böylece tek çalıştırmada dosyadaki
+            // semantik hatalar da listelenir. Sonraki aşamalar çalıştırılmaz
+            result.stages.push((Stage::Parse, StageStatus::Failed));
+        }
+
+        let mut analyzers = Vec::new();
+        for unit in result.units.iter_mut() {
+            self.error_reporter.set_file(&unit.name);
+            let mut analyzer = analyze(&unit.ast, &self.target);
+            self.error_reporter.extend(analyzer.take_diagnostics());
+            unit.symbol_table = Some(analyzer.symbol_table.clone());
+            analyzers.push(analyzer);
+        }
+        let analyzed = self.error_reporter.error_count() == parse_errors;
+        if !result.record(Stage::Semantic, analyzed, if parse_errors == 0 { last_stage } else { Stage::Semantic }) {
+            return;
+        }
+
+        // Her dosyanın kendi MemoryManager'ı vardır; statik bölgeler çakışmasın diye
+        // her dosya bir öncekinin bittiği adresten başlar
+        let mut memory_managers: Vec<MemoryManager> = Vec::new();
+        for (unit, analyzer) in result.units.iter_mut().zip(&analyzers) {
+            self.error_reporter.set_file(&unit.name);
+            let static_base = memory_managers.last().map_or(0x1000, |m| m.next_static_address()); // Statik bellek başlangıç adresi
+            let memory_manager = self.layout(&unit.ast, analyzer, static_base);
+            unit.memory = Some(memory_manager.layout());
+            memory_managers.push(memory_manager);
+        }
+        let multiple = result.units.len() > 1;
+        let mut map = Vec::new();
+        for unit in &result.units {
+            if multiple {
+                map.push(format!("; {}", unit.name));
+            }
+            map.extend(unit.memory.iter().flatten().map(|(name, allocation)| memory_map_line(name, allocation)));
+        }
+        result.artifacts.map = Some(map);
+        if !result.record(Stage::Layout, !self.error_reporter.has_errors(), last_stage) {
+            return;
+        }
+
+        let mut listing = Vec::new();
+        for ((unit, analyzer), memory_manager) in result.units.iter_mut().zip(analyzers).zip(memory_managers) {
+            let mut code_generator = CodeGenerator::new(analyzer.symbol_table.clone(), memory_manager);
+            let generated = code_generator.generate_code(&unit.ast).clone();
+            self.error_reporter.set_file(&unit.name);
+            self.error_reporter.extend(std::mem::take(&mut code_generator.diagnostics));
+            if multiple {
+                listing.push(format!("; {}", unit.name));
+            }
+            listing.extend(generated.iter().enumerate().map(|(index, line)| format!("{:>5}  {}", index + 1, line)));
+            unit.object = Some(ObjectFile {
+                name: unit.name.clone(),
+                code: generated,
+                definitions: analyzer
+                    .symbol_table
+                    .names_where(|symbol| matches!(symbol.symbol_type, SymbolType::Label | SymbolType::Procedure)),
+                globals: analyzer.extern_symbol_table.names_with_binding(SymbolBinding::Global),
+                externs: analyzer.extern_symbol_table.names_with_binding(SymbolBinding::External),
+            });
+        }
+        result.artifacts.listing = Some(listing);
+        if !result.record(Stage::Codegen, !self.error_reporter.has_errors(), last_stage) {
+            return;
+        }
+
+        let objects: Vec<ObjectFile> = result.units.iter().filter_map(|unit| unit.object.clone()).collect();
+        match Linker::new().link_objects(&objects) {
+            Ok(linked) => result.artifacts.object = Some(linked),
+            Err(errors) => {
+                for error in errors {
+                    self.error_reporter.set_file(&error.object);
+                    self.error_reporter.report_error(error.code, error.message, None);
+                }
+            }
+        }
+        result.record(Stage::Link, !self.error_reporter.has_errors(), last_stage);
+    }
+
+    // Sembol tablosunu kullanarak statik değişkenler ve handle'lar için bellek/kayıt ayırır
+    fn layout(&mut self, ast: &AstNode, semantic_analyzer: &SemanticAnalyzer, static_base: usize) -> MemoryManager {
+        let mut memory_manager = MemoryManager::new(static_base);
+        if let AstNode::Program(statements) = ast {
+            let mut section = Section::default();
+            for statement in statements {
+                let allocation = match &statement.node {
+                    AstNode::Section { section: next } => {
+                        memory_manager.close_data_label();
+                        section = *next;
+                        continue;
+                    }
+                    AstNode::Label { name } if section == Section::Data => memory_manager.allocate_data(name),
+                    AstNode::DataDirective { kind, values } => {
+                        memory_manager.reserve_data(data_size(*kind, values));
+                        continue;
+                    }
+                    AstNode::Instruction { opcode, operands } if opcode == "VAR" && operands.len() == 2 => {
+                        if let (Expression::Identifier(name), Expression::Identifier(type_name)) = (&operands[0], &operands[1]) {
+                            let size = semantic_analyzer.resolve_type(type_name).and_then(|t| t.size()).unwrap_or(4); // Varsayılan 4
+                            memory_manager.allocate_static(name, size)
+                        } else {
+                            continue;
+                        }
+                    }
+                    AstNode::AllocateMemory { handle, .. } |
+                    AstNode::AcquireResource { handle, .. } |
+                    AstNode::GetTaskId { target: handle } |
+                    AstNode::GetCoreId { target: handle } |
+                    AstNode::GetTotalCores { target: handle } => {
+                        memory_manager.allocate_handle(handle)
+                    }
+                    _ => continue,
+                };
+                if let Err(error) = allocation {
+                    self.error_reporter.report_error_at(error.code, error.message, Some(statement.location));
+                }
+            }
+        }
+        memory_manager
+    }
+}
+
+fn read_sources(provider: &dyn FileProvider, input_filenames: &[&str]) -> Result<Vec<SourceFile>, io::Error> {
+    input_filenames.iter().map(|name| provider.read_source(name)).collect()
+}
+
+fn analyze(ast: &AstNode, target: &Target) -> SemanticAnalyzer {
+    let mut semantic_analyzer = SemanticAnalyzer::with_target(target.clone());
+    semantic_analyzer.analyze(ast);
+    semantic_analyzer.analyze_control_flow(ast);
+    semantic_analyzer.analyze_functions(ast);
+    semantic_analyzer.analyze_variables(ast);
+    semantic_analyzer.analyze_operators(ast);
+    semantic_analyzer.analyze_io(ast);
+    semantic_analyzer
+}
+
+// Veri direktifinin kapladığı bayt sayısı; .string literalleri UTF-8 uzunluğuyla sayılır
+fn data_size(kind: DataKind, values: &[Expression]) -> usize {
+    let width = match kind {
+        DataKind::String | DataKind::Byte => 1,
+        DataKind::Word => 2,
+        DataKind::DWord => 4,
+        DataKind::QWord => 8,
+    };
+    values
+        .iter()
+        .map(|value| match (kind, value) {
+            (DataKind::String, Expression::StringLiteral(text)) => text.len(),
+            _ => width,
+        })
+        .sum()
+}
+
+// Bellek haritası satırı: "bölüm ad adres boyut"
+pub(crate) fn memory_map_line(name: &str, allocation: &MemoryAllocation) -> String {
+    match allocation.section {
+        MemorySection::Static => format!("static  {:<24} 0x{:08X} {}", name, allocation.address, allocation.size),
+        MemorySection::Data => format!("data    {:<24} 0x{:08X} {}", name, allocation.address, allocation.size),
+        MemorySection::Handle => format!("handle  {:<24} -          {}", name, allocation.size),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_provider::MemoryFileProvider;
+
+    #[test]
+    fn test_compile_result_reports_stages_and_artifacts() {
+        let mut compiler = Compiler::new();
+        let result = compiler.compile_source("FLAG Z\nRELEASE 5".to_string());
+
+        assert!(result.is_success());
+        assert!(result.diagnostics.is_empty());
+        for stage in [Stage::Parse, Stage::Semantic, Stage::Layout, Stage::Codegen, Stage::Link] {
+            assert_eq!(result.stage_status(stage), StageStatus::Succeeded);
+        }
+        assert_eq!(result.artifacts.object, Some(vec!["SYS_CALL 2".to_string(), "ARG 5".to_string()]));
+        assert_eq!(result.artifacts.listing.as_ref().map(|l| l.len()), Some(2));
+        assert_eq!(result.artifacts.map, Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_compile_sources_in_memory() {
+        let mut compiler = Compiler::new();
+        let sources = [SourceFile::new("a.b64", "FLAG Z"), SourceFile::new("b.b64", "RELEASE 7")];
+        let result = compiler.compile_sources(&sources);
+
+        assert!(result.is_success());
+        assert_eq!(result.units.len(), 2);
+        assert_eq!(result.units[0].name, "a.b64");
+        assert!(result.units[0].object.as_ref().unwrap().code.is_empty());
+        assert_eq!(result.units[1].object.as_ref().unwrap().code, vec!["SYS_CALL 2".to_string(), "ARG 7".to_string()]);
+        assert_eq!(result.artifacts.object, Some(vec!["SYS_CALL 2".to_string(), "ARG 7".to_string()]));
+    }
+
+    #[test]
+    fn test_compile_through_memory_provider() {
+        let mut provider = MemoryFileProvider::new();
+        provider.insert("main.b64", "RELEASE 1");
+        let mut compiler = Compiler::new();
+
+        let result = compiler.compile(&mut provider, &["main.b64"], "main.bs64").unwrap();
+        assert!(result.is_success());
+        assert_eq!(provider.get("main.bs64"), Some("SYS_CALL 2\nARG 1\n"));
+
+        let checked = compiler.check(&provider, &["main.b64"]).unwrap();
+        assert!(checked.is_success());
+        assert_eq!(checked.stage_status(Stage::Codegen), StageStatus::Skipped);
+        let error = compiler.check(&provider, &["eksik.b64"]).unwrap_err();
+        let message = error.get_ref().and_then(|inner| inner.downcast_ref::<crate::messages::Message>()).unwrap();
+        assert_eq!(message.text(Language::English), "'eksik.b64' not found");
+    }
+
+    #[test]
+    fn test_compile_multiple_files_links_globals_and_externs() {
+        let mut compiler = Compiler::new();
+        let sources = [
+            SourceFile::new("main.b64", "EXTERN WORKER\nJUMP WORKER"),
+            SourceFile::new("worker.b64", "GLOBAL WORKER\nWORKER:\nRELEASE 1"),
+        ];
+        let result = compiler.compile_sources(&sources);
+
+        assert!(result.is_success(), "{:?}", result.diagnostics);
+        assert_eq!(result.units[0].object.as_ref().unwrap().externs, vec!["WORKER".to_string()]);
+        assert_eq!(result.units[1].object.as_ref().unwrap().globals, vec!["WORKER".to_string()]);
+        assert_eq!(
+            result.artifacts.object,
+            Some(vec!["JUMP WORKER".to_string(), "WORKER:".to_string(), "SYS_CALL 2".to_string(), "ARG 1".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_unresolved_extern_fails_link_stage() {
+        let mut compiler = Compiler::new();
+        let result = compiler.compile_sources(&[SourceFile::new("main.b64", "EXTERN WORKER\nJUMP WORKER")]);
+
+        assert_eq!(result.stage_status(Stage::Link), StageStatus::Failed);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].file.as_deref(), Some("main.b64"));
+        assert!(result.artifacts.object.is_none());
+    }
+
+    #[test]
+    fn test_all_errors_in_a_file_are_reported_with_locations() {
+        let mut compiler = Compiler::new();
+        let messages = |result: &CompileResult| result.diagnostics.iter().map(|d| d.to_string()).collect::<Vec<_>>();
+
+        let result = compiler.compile_sources(&[SourceFile::new("main.b64", "JUMP 5\nFLAG Z\nRELEASE :\nGLOBAL 7")]);
+        assert_eq!(result.stage_status(Stage::Parse), StageStatus::Failed);
+        assert_eq!(messages(&result), vec![
+            "main.b64: Hata (Satır: 1, Sütun: 6): JUMP komutundan sonra hedef bekleniyor, '5' bulundu",
+            "main.b64: Hata (Satır: 3, Sütun: 9): İfade bekleniyor, ':' bulundu",
+            "main.b64: Hata (Satır: 4, Sütun: 8): GLOBAL direktifinden sonra sembol adı bekleniyor, '7' bulundu",
+        ]);
+
+        let result = compiler.compile_sources(&[SourceFile::new("main.b64", "JUMP NOWHERE\nSTART:\nSTART:\nRELEASE \"x\"")]);
+        assert_eq!(result.stage_status(Stage::Semantic), StageStatus::Failed);
+        assert_eq!(messages(&result), vec![
+            "main.b64: Hata (Satır: 1, Sütun: 1): Tanımsız etiket 'NOWHERE'",
+            "main.b64: Uyarı (Satır: 2, Sütun: 1): Etiket 'START' hiç kullanılmıyor",
+            "main.b64: Hata (Satır: 3, Sütun: 1): Sembol 'START' zaten bu kapsamda tanımlı",
+            "main.b64: Uyarı (Satır: 3, Sütun: 1): Etiket 'START' hiç kullanılmıyor",
+            "main.b64: Hata (Satır: 4, Sütun: 9): Tip uyuşmazlığı: String beklenmiyordu",
+        ]);
+        let codes: Vec<&str> = result.diagnostics.iter().map(|d| d.code.as_str()).collect();
+        assert_eq!(codes, vec!["B0202", "B0601", "B0204", "B0601", "B0301"]);
+        assert!(result.render_diagnostics(MessageFormat::Human, false, Language::Turkish).ends_with(concat!(
+            "Ayrıntılı açıklaması olan hatalar: B0202, B0204, B0301.\n",
+            "Bir hata hakkında daha fazla bilgi için 'bessc --explain B0202' komutunu kullanın.\n",
+        )));
+        assert!(result.render_diagnostics(MessageFormat::Human, false, Language::English).ends_with(concat!(
+            "Some errors have detailed explanations: B0202, B0204, B0301.\n",
+            "For more information about an error, try 'bessc --explain B0202'.\n",
+        )));
+    }
+
+    #[test]
+    fn test_statements_recovered_after_parse_errors_are_still_analyzed() {
+        let result = Compiler::new().compile_sources(&[SourceFile::new("main.b64", "JUMP 5\nJLT NOWHERE\nALLOCATE 4 AS H\nRECV H, BUF")]);
+        let codes: Vec<&str> = result.diagnostics.iter().map(|d| d.code.as_str()).collect();
+        assert_eq!(codes, vec!["B0105", "B0202", "B0201"]);
+        assert_eq!(result.stage_status(Stage::Parse), StageStatus::Failed);
+        assert_eq!(result.stage_status(Stage::Semantic), StageStatus::Failed);
+        assert_eq!(result.stage_status(Stage::Codegen), StageStatus::Skipped);
+        assert!(result.artifacts.listing.is_none() && result.artifacts.object.is_none());
+    }
+
+    #[test]
+    fn test_registers_are_checked_against_the_target() {
+        let source = [SourceFile::new("main.b64", "RELEASE R7\nRELEASE R8\nLOAD R0, R01")];
+        let result = Compiler::new().compile_sources(&source);
+        let messages: Vec<String> = result.diagnostics.iter().map(|d| d.to_string()).collect();
+        assert_eq!(messages, vec![
+            "main.b64: Hata (Satır: 2, Sütun: 9): 'sahne64' hedefinde 'R8' registerı yok; kullanılabilir registerlar R0-R7",
+            "main.b64: Hata (Satır: 3, Sütun: 10): Tanımsız değişken 'R01'",
+        ]);
+        assert_eq!(result.diagnostics[0].code.as_str(), "B0308");
+        // Semantik hatalar bütün deyimi değil yalnızca hatalı işleneni işaretler
+        assert!(result.render_diagnostics(MessageFormat::Human, false, Language::Turkish).contains("2 | RELEASE R8\n  |         ^^\n"));
+
+        let mut compiler = Compiler::new();
+        compiler.set_target(Target::sahne64().with_register_count(16));
+        let result = compiler.compile_sources(&[SourceFile::new("main.b64", "RELEASE R15")]);
+        assert!(result.is_success());
+        assert_eq!(result.artifacts.object, Some(vec!["SYS_CALL 2".to_string(), "ARG R15".to_string()]));
+    }
+
+    #[test]
+    fn test_address_of_resolves_data_labels_through_the_layout() {
+        let source = [SourceFile::new(
+            "main.b64",
+            ".data\nMSG: .string \"Merhaba\"\nMAX: .word 1, 2\n.code\nSTART:\nRELEASE $MSG\nRELEASE $MAX\nRELEASE $START",
+        )];
+        let result = Compiler::new().compile_sources(&source);
+        assert!(result.is_success(), "{:?}", result.diagnostics);
+        assert_eq!(result.artifacts.map, Some(vec![
+            "data    MSG                      0x00001000 7".to_string(),
+            "data    MAX                      0x00001007 4".to_string(),
+        ]));
+        let object = result.artifacts.object.unwrap();
+        assert!(object.contains(&"ARG 4096".to_string()));
+        assert!(object.contains(&"ARG 4103".to_string()));
+        assert!(object.contains(&"ARG $START".to_string()));
+
+        // Dış sembolün adresi bağlayıcıya "$ad" olarak bırakılır
+        let sources = [
+            SourceFile::new("main.b64", "EXTERN BUFFER\nRELEASE $BUFFER"),
+            SourceFile::new("buffer.b64", "GLOBAL BUFFER\nBUFFER:\nEXIT"),
+        ];
+        let result = Compiler::new().compile_sources(&sources);
+        assert!(result.is_success(), "{:?}", result.diagnostics);
+        assert!(result.artifacts.object.unwrap().contains(&"ARG $BUFFER".to_string()));
+
+        let result = Compiler::new().compile_sources(&[SourceFile::new("main.b64", "RELEASE $MISSING")]);
+        let codes: Vec<&str> = result.diagnostics.iter().map(|d| d.code.as_str()).collect();
+        assert_eq!(codes, vec!["B0202"]);
+        assert_eq!(result.diagnostics[0].to_string(), "main.b64: Hata (Satır: 1, Sütun: 9): Tanımsız etiket 'MISSING'");
+
+        let result = Compiler::new().compile_sources(&[SourceFile::new("main.b64", "RELEASE $5")]);
+        let messages: Vec<String> = result.diagnostics.iter().map(|d| d.to_string()).collect();
+        assert_eq!(messages, vec![
+            "main.b64: Hata (Satır: 1, Sütun: 10): '$' işaretinden sonra sembol adı bekleniyor, '5' bulundu",
+        ]);
+    }
+
+    #[test]
+    fn test_data_labels_are_accepted_as_names_memory_and_addresses() {
+        for operand in ["MSG", "[MSG]", "$MSG"] {
+            let source = format!(".data\nMSG: .word 1\n.code\nLOAD R1, {}\nMOV {}, R1", operand, operand.replace('$', ""));
+            let result = Compiler::new().compile_sources(&[SourceFile::new("main.b64", &source)]);
+            assert!(result.is_success(), "{}: {:?}", operand, result.diagnostics);
+            assert!(result.diagnostics.is_empty(), "{}: {:?}", operand, result.diagnostics);
+        }
+
+        let result = Compiler::new().compile_sources(&[SourceFile::new("main.b64", "LOAD R1, MISSING")]);
+        let codes: Vec<&str> = result.diagnostics.iter().map(|d| d.code.as_str()).collect();
+        assert_eq!(codes, vec!["B0201"]);
+    }
+
+    #[test]
+    fn test_data_directive_values_are_checked_against_their_width() {
+        let source = [SourceFile::new("main.b64", ".data\nB1: .byte 255, -128, 300\nW1: .word 70000\nD1: .dword 0xFFFFFFFF\nQ1: .qword -1")];
+        let result = Compiler::new().compile_sources(&source);
+        assert!(result.is_success(), "{:?}", result.diagnostics);
+        let truncated: Vec<String> = result.diagnostics.iter().filter(|d| d.code.as_str() == "B0603").map(|d| d.to_string()).collect();
+        assert_eq!(truncated, vec![
+            "main.b64: Uyarı (Satır: 2, Sütun: 22): Sabit 300 'BYTE' tipine sığmıyor ve kesilecek",
+            "main.b64: Uyarı (Satır: 3, Sütun: 11): Sabit 70000 'WORD' tipine sığmıyor ve kesilecek",
+        ]);
+    }
+
+    #[test]
+    fn test_labels_and_procedures_can_be_used_before_their_definition() {
+        let source = [SourceFile::new("main.b64", "START:\nCMP R0, 0\nJLT FAIL\nCALL WORK\nJUMP START\nPROCEDURE_WORK:\nRET\nFAIL:\nEXIT 1")];
+        let result = Compiler::new().compile_sources(&source);
+        assert!(result.is_success(), "{:?}", result.diagnostics);
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+
+        let result = Compiler::new().compile_sources(&[SourceFile::new("main.b64", "JLT NOWHERE\nCALL NOBODY")]);
+        let codes: Vec<&str> = result.diagnostics.iter().map(|d| d.code.as_str()).collect();
+        assert_eq!(codes, vec!["B0202", "B0203"]);
+    }
+
+    #[test]
+    fn test_unicode_identifiers_are_normalized_and_confusables_reported() {
+        let source = [SourceFile::new("main.b64", "şişe:\nJUMP s\u{0327}is\u{0327}e\nsayı:\nJUMP sayı\nsayi:\nJUMP sayi")];
+        let result = Compiler::new().compile_sources(&source);
+        assert!(result.is_success(), "{:?}", result.diagnostics);
+        let messages: Vec<String> = result.diagnostics.iter().map(|d| d.to_string()).collect();
+        assert_eq!(messages, vec!["main.b64: Uyarı (Satır: 5, Sütun: 1): 'sayi' adı 'sayı' ile karıştırılabilir"]);
+        assert_eq!(result.diagnostics[0].lint, Some(Lint::ConfusableIdentifier));
+    }
+
+    #[test]
+    fn test_lint_levels_and_warnings_as_errors() {
+        let source = [SourceFile::new("main.b64", "GLOBAL START\nGLOBAL START\nSTART:\nJUMP START\nRELEASE 1\nEND:")];
+        let warnings = |result: &CompileResult| {
+            result.diagnostics.iter().map(|d| (d.severity, d.lint.map(|lint| lint.name()))).collect::<Vec<_>>()
+        };
+
+        let result = Compiler::new().compile_sources(&source);
+        assert!(result.is_success());
+        assert_eq!(warnings(&result), vec![
+            (Severity::Warning, Some("redundant-declaration")),
+            (Severity::Warning, Some("unreachable-code")),
+            (Severity::Warning, Some("unused-label")),
+        ]);
+
+        let mut compiler = Compiler::new();
+        compiler.set_lint_level(Lint::UnreachableCode, LintLevel::Allow);
+        compiler.set_lint_level(Lint::UnusedLabel, LintLevel::Allow);
+        compiler.set_lint_level(Lint::RedundantDeclaration, LintLevel::Deny);
+        let result = compiler.compile_sources(&source);
+        assert!(!result.is_success());
+        assert_eq!(warnings(&result), vec![(Severity::Error, Some("redundant-declaration"))]);
+
+        let mut compiler = Compiler::new();
+        compiler.set_warnings_as_errors(true);
+        let result = compiler.compile_sources(&source);
+        assert_eq!(result.stage_status(Stage::Semantic), StageStatus::Failed);
+        assert!(result.diagnostics.iter().all(|d| d.severity == Severity::Error));
+
+        let mut analyzer = SemanticAnalyzer::new();
+        analyzer.analyze_expression(&Expression::Number(300), Some(&crate::types::TYPE_BYTE));
+        analyzer.analyze_expression(&Expression::Number(-128), Some(&crate::types::TYPE_BYTE));
+        let lints: Vec<_> = analyzer.diagnostics().iter().map(|d| d.lint).collect();
+        assert_eq!(lints, vec![Some(Lint::TruncatingImmediate)]);
+    }
+}
@@ -1,93 +1,191 @@
-use crate::parser::AstNode;
-use crate::semantic_analyzer::SemanticAnalyzer;
-
-impl SemanticAnalyzer {
-    pub fn analyze_control_flow(&mut self, ast: &AstNode) {
-        match ast {
-            AstNode::Program(statements) => {
-                for statement in statements {
-                    self.analyze_control_flow_statement(statement);
-                }
-            }
-            _ => {}
-        }
-    }
-
-    fn analyze_control_flow_statement(&mut self, node: &AstNode) {
-        match node {
-            AstNode::JumpStatement { target } => {
-                println!("Kontrol akışı: JUMP -> {}", target);
-            }
-            AstNode::FlagDeclaration { flag } => {
-                println!("Kontrol akışı: FLAG {}", flag);
-            }
-            AstNode::Label { name } => {
-                println!("Kontrol akışı: Etiket '{}'", name);
-            }
-            AstNode::SpawnTask { function, priority } => {
-                println!("Kontrol akışı: SPAWN yeni görev '{}' (öncelik: {:?})", function, priority);
-                // Burada yeni bir kontrol akışı bloğu başlayabilir (ileride daha detaylı analiz için)
-            }
-            AstNode::ExitTask { code } => {
-                println!("Kontrol akışı: EXIT görev (kod: {:?})", code);
-                // Bu noktadan sonraki kod ulaşılamaz olabilir (ileride analiz edilebilir)
-            }
-            AstNode::YieldTask => {
-                println!("Kontrol akışı: YIELD görev");
-                // Görev zamanlayıcıya kontrolü bırakır
-            }
-            AstNode::Instruction { opcode, operands } => {
-                println!("Kontrol akışı: {} {:?}", opcode, operands);
-                // CALL, RET gibi opcodelar kontrol akışını değiştirebilir
-            }
-            AstNode::Assignment { .. } |
-            AstNode::AllocateMemory { .. } |
-            AstNode::ReleaseMemory { .. } |
-            AstNode::AcquireResource { .. } |
-            AstNode::ControlResource { .. } |
-            AstNode::SendMessage { .. } |
-            AstNode::ReceiveMessage { .. } |
-            AstNode::GetTaskId { .. } |
-            AstNode::GetCoreId { .. } |
-            AstNode::GetTotalCores { .. } => {
-                // Bu API çağrıları genellikle doğrudan kontrol akışını değiştirmez
-            }
-            _ => {}
-        }
-    }
-
-    // İleride daha karmaşık kontrol akışı analizleri eklenebilir:
-    // - Görevler arası senkronizasyon analizi (ACQUIRE/RELEASE)
-    // - Mesajlaşma örüntüleri analizi (SEND/RECV)
-    // - Olası dead-lock durumları (basit düzeyde)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::lexer::Lexer;
-    use crate::parser::Parser;
-    use crate::semantic_analyzer::SemanticAnalyzer;
-
-    #[test]
-    fn test_control_flow_analysis_with_sahne64() {
-        let input = "
-            start:
-                ALLOCATE 1024 AS handle1
-                SPAWN worker_func WITH prio=5
-                JUMP target
-            target:
-                YIELD
-                EXIT 0
-        ";
-        let lexer = Lexer::new(input.to_string());
-        let mut parser = Parser::new(lexer);
-        let ast = parser.parse_program();
-
-        let mut analyzer = SemanticAnalyzer::new();
-        analyzer.analyze(&ast); // Önce semantik analiz yapılmalı
-
-        analyzer.analyze_control_flow(&ast);
-        // Bu test şu anda sadece çıktı üretiyor, daha detaylı analizler eklenebilir.
-    }
+use crate::error_reporter::{Diagnostic, Lint};
+use crate::messages::msg;
+use crate::parser::{AstNode, Expression, Statement};
+use crate::semantic_analyzer::SemanticAnalyzer;
+use std::collections::HashSet;
+
+impl SemanticAnalyzer {
+    pub fn analyze_control_flow(&mut self, ast: &AstNode) {
+        if let AstNode::Program(statements) = ast {
+            for statement in statements {
+                self.enter_statement(statement);
+                self.analyze_control_flow_statement(&statement.node);
+            }
+            self.check_unreachable_code(statements);
+            self.check_unused_labels(statements);
+        }
+    }
+
+    // Koşulsuz JUMP veya EXIT'ten sonra, bir etikete kadar gelen kod hiç çalışmaz
+    fn check_unreachable_code(&mut self, statements: &[Statement]) {
+        let mut terminator: Option<&Statement> = None;
+        let mut reported = false; // Aynı blok için tek uyarı yeter
+        for statement in statements {
+            match &statement.node {
+                AstNode::Label { .. } | AstNode::Section { .. } => {
+                    terminator = None;
+                    reported = false;
+                }
+                // Direktifler ve veri tanımları çalıştırılacak kod üretmez
+                AstNode::Instruction { opcode, .. } if opcode == "GLOBAL" || opcode == "EXTERN" => {}
+                AstNode::DataDirective { .. } | AstNode::Constant { .. } => {}
+                _ if reported => {}
+                _ => match terminator {
+                    Some(previous) => {
+                        self.current_location = Some(statement.location);
+                        let diagnostic = Diagnostic::warning(Lint::UnreachableCode, msg!("B0604"), Some(statement.location))
+                            .with_label(previous.location, msg!("label.flow-ends"))
+                            .with_help(msg!("help.unreachable-code"));
+                        self.report(diagnostic);
+                        reported = true;
+                    }
+                    None => {
+                        if matches!(statement.node, AstNode::JumpStatement { .. } | AstNode::ExitTask { .. }) {
+                            terminator = Some(statement);
+                        }
+                    }
+                },
+            }
+        }
+    }
+
+    // Hiçbir JUMP, SPAWN veya komut işleneninde geçmeyen etiketler. Prosedürler
+    // dışarıdan çağrılmak üzere yazıldığından bu kontrolün dışında tutulur.
+    fn check_unused_labels(&mut self, statements: &[Statement]) {
+        let mut referenced: HashSet<&str> = HashSet::new();
+        for statement in statements {
+            match &statement.node {
+                AstNode::JumpStatement { target } => {
+                    referenced.insert(target);
+                }
+                AstNode::SpawnTask { function, priority } => {
+                    referenced.insert(function);
+                    priority.iter().for_each(|value| collect_identifiers(value, &mut referenced));
+                }
+                // Sistem çağrısı operandlarındaki "$etiket" de etiketi kullanır
+                AstNode::Assignment { value, .. }
+                | AstNode::AllocateMemory { size: value, .. }
+                | AstNode::ReleaseMemory { handle: value }
+                | AstNode::SleepTask { duration: value }
+                | AstNode::AcquireResource { name: value, .. }
+                | AstNode::ReceiveMessage { handle: value, .. }
+                | AstNode::ExitTask { code: Some(value) } => collect_identifiers(value, &mut referenced),
+                AstNode::ControlResource { handle, command: value } | AstNode::SendMessage { handle, message: value } => {
+                    collect_identifiers(handle, &mut referenced);
+                    collect_identifiers(value, &mut referenced);
+                }
+                AstNode::Instruction { operands: values, .. } | AstNode::DataDirective { values, .. } => {
+                    for value in values {
+                        collect_identifiers(value, &mut referenced);
+                    }
+                }
+                AstNode::Constant { value, .. } => collect_identifiers(value, &mut referenced),
+                _ => {}
+            }
+        }
+
+        for statement in statements {
+            if let AstNode::Label { name } = &statement.node {
+                if name.starts_with("PROCEDURE_") || referenced.contains(name.as_str()) {
+                    continue;
+                }
+                self.current_location = Some(statement.location);
+                let diagnostic = Diagnostic::warning(Lint::UnusedLabel, msg!("B0601", name), Some(statement.location))
+                    .with_help(msg!("help.unused-label", name));
+                self.report(diagnostic);
+            }
+        }
+    }
+
+    fn analyze_control_flow_statement(&mut self, node: &AstNode) {
+        match node {
+            AstNode::JumpStatement { target } => {
+                self.control_flow_trace.push(format!("Kontrol akışı: JUMP -> {}", target));
+            }
+            AstNode::FlagDeclaration { flag } => {
+                self.control_flow_trace.push(format!("Kontrol akışı: FLAG {}", flag));
+            }
+            AstNode::Label { name } => {
+                self.control_flow_trace.push(format!("Kontrol akışı: Etiket '{}'", name));
+            }
+            AstNode::SpawnTask { function, priority } => {
+                self.control_flow_trace.push(format!("Kontrol akışı: SPAWN yeni görev '{}' (öncelik: {:?})", function, priority));
+                // Burada yeni bir kontrol akışı bloğu başlayabilir (ileride daha detaylı analiz için)
+            }
+            AstNode::ExitTask { code } => {
+                self.control_flow_trace.push(format!("Kontrol akışı: EXIT görev (kod: {:?})", code));
+                // Bu noktadan sonraki kod ulaşılamaz olabilir (ileride analiz edilebilir)
+            }
+            AstNode::YieldTask => {
+                self.control_flow_trace.push("Kontrol akışı: YIELD görev".to_string());
+                // Görev zamanlayıcıya kontrolü bırakır
+            }
+            AstNode::Instruction { opcode, operands } => {
+                self.control_flow_trace.push(format!("Kontrol akışı: {} {:?}", opcode, operands));
+                // CALL, RET gibi opcodelar kontrol akışını değiştirebilir
+            }
+            AstNode::Assignment { .. } |
+            AstNode::AllocateMemory { .. } |
+            AstNode::ReleaseMemory { .. } |
+            AstNode::AcquireResource { .. } |
+            AstNode::ControlResource { .. } |
+            AstNode::SendMessage { .. } |
+            AstNode::ReceiveMessage { .. } |
+            AstNode::GetTaskId { .. } |
+            AstNode::GetCoreId { .. } |
+            AstNode::GetTotalCores { .. } => {
+                // Bu API çağrıları genellikle doğrudan kontrol akışını değiştirmez
+            }
+            _ => {}
+        }
+    }
+
+    // İleride daha karmaşık kontrol akışı analizleri eklenebilir:
+    // - Görevler arası senkronizasyon analizi (ACQUIRE/RELEASE)
+    // - Mesajlaşma örüntüleri analizi (SEND/RECV)
+    // - Olası dead-lock durumları (basit düzeyde)
+}
+
+// İfadede geçen adlar ("hello_len = . - hello_msg" için hello_msg, "$console_id" için console_id)
+fn collect_identifiers<'a>(expression: &'a Expression, names: &mut HashSet<&'a str>) {
+    match expression {
+        Expression::Identifier(name) | Expression::AddressOf(name) => {
+            names.insert(name);
+        }
+        Expression::Binary { left, right, .. } => {
+            collect_identifiers(left, names);
+            collect_identifiers(right, names);
+        }
+        Expression::Memory(address) => collect_identifiers(address, names),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lexer::Lexer;
+    use crate::parser::Parser;
+    use crate::semantic_analyzer::SemanticAnalyzer;
+
+    #[test]
+    fn test_control_flow_analysis_with_sahne64() {
+        let input = "
+            start:
+                ALLOCATE 1024 AS handle1
+                SPAWN worker_func WITH prio=5
+                JUMP target
+            target:
+                YIELD
+                EXIT 0
+        ";
+        let lexer = Lexer::new(input.to_string());
+        let mut parser = Parser::new(lexer);
+        let ast = parser.parse_program();
+
+        let mut analyzer = SemanticAnalyzer::new();
+        analyzer.analyze(&ast); // Önce semantik analiz yapılmalı
+
+        analyzer.analyze_control_flow(&ast);
+        // Bu test şu anda sadece control_flow_trace kaydı üretiyor, daha detaylı analizler eklenebilir.
+    }
 }
\ No newline at end of file
@@ -0,0 +1,258 @@
+use crate::messages::{self, Language};
+use std::fmt;
+
+// Her tanılamanın kalıcı kodu. Kodlar mesaj metninden bağımsızdır; mesajlar
+// değişse de araçlar, belgeler ve aramalar aynı koda güvenebilir. Numaralar
+// aşamaya göre gruplanır ve bir kez yayımlanan kod başka bir anlamla yeniden
+// kullanılmaz:
+//   B01xx sözdizimi, B02xx adlar ve kapsamlar, B03xx tipler ve işlenenler,
+//   B04xx bellek yerleşimi, B05xx bağlama, B06xx uyarılar, B09xx iç hatalar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticCode {
+    UnexpectedCharacter,
+    UnterminatedString,
+    UnexpectedToken,
+    ExpectedExpression,
+    MissingOperand,
+    UnknownDirective,
+    InvalidNumber,
+    NumberOverflow,
+    InvalidEscape,
+    ExpectedEndOfLine,
+    UndefinedVariable,
+    UndefinedLabel,
+    UndefinedProcedure,
+    Redefinition,
+    ConflictingLinkage,
+    UnknownType,
+    TypeMismatch,
+    NonNumericOperand,
+    NonComparableOperand,
+    NonLogicalOperand,
+    OperandCount,
+    InvalidOperand,
+    ApiCall,
+    InvalidRegister,
+    DuplicateStatic,
+    DuplicateHandle,
+    UndefinedHandle,
+    UndefinedGlobal,
+    DuplicateGlobal,
+    UnresolvedExtern,
+    UnusedLabel,
+    ShadowedSymbol,
+    TruncatingImmediate,
+    UnreachableCode,
+    RedundantDeclaration,
+    ConfusableIdentifier,
+    Internal,
+}
+
+impl DiagnosticCode {
+    pub const ALL: [DiagnosticCode; 37] = [
+        DiagnosticCode::UnexpectedCharacter,
+        DiagnosticCode::UnterminatedString,
+        DiagnosticCode::UnexpectedToken,
+        DiagnosticCode::ExpectedExpression,
+        DiagnosticCode::MissingOperand,
+        DiagnosticCode::UnknownDirective,
+        DiagnosticCode::InvalidNumber,
+        DiagnosticCode::NumberOverflow,
+        DiagnosticCode::InvalidEscape,
+        DiagnosticCode::ExpectedEndOfLine,
+        DiagnosticCode::UndefinedVariable,
+        DiagnosticCode::UndefinedLabel,
+        DiagnosticCode::UndefinedProcedure,
+        DiagnosticCode::Redefinition,
+        DiagnosticCode::ConflictingLinkage,
+        DiagnosticCode::UnknownType,
+        DiagnosticCode::TypeMismatch,
+        DiagnosticCode::NonNumericOperand,
+        DiagnosticCode::NonComparableOperand,
+        DiagnosticCode::NonLogicalOperand,
+        DiagnosticCode::OperandCount,
+        DiagnosticCode::InvalidOperand,
+        DiagnosticCode::ApiCall,
+        DiagnosticCode::InvalidRegister,
+        DiagnosticCode::DuplicateStatic,
+        DiagnosticCode::DuplicateHandle,
+        DiagnosticCode::UndefinedHandle,
+        DiagnosticCode::UndefinedGlobal,
+        DiagnosticCode::DuplicateGlobal,
+        DiagnosticCode::UnresolvedExtern,
+        DiagnosticCode::UnusedLabel,
+        DiagnosticCode::ShadowedSymbol,
+        DiagnosticCode::TruncatingImmediate,
+        DiagnosticCode::UnreachableCode,
+        DiagnosticCode::RedundantDeclaration,
+        DiagnosticCode::ConfusableIdentifier,
+        DiagnosticCode::Internal,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiagnosticCode::UnexpectedCharacter => "B0101",
+            DiagnosticCode::UnterminatedString => "B0102",
+            DiagnosticCode::UnexpectedToken => "B0103",
+            DiagnosticCode::ExpectedExpression => "B0104",
+            DiagnosticCode::MissingOperand => "B0105",
+            DiagnosticCode::UnknownDirective => "B0106",
+            DiagnosticCode::InvalidNumber => "B0107",
+            DiagnosticCode::NumberOverflow => "B0108",
+            DiagnosticCode::InvalidEscape => "B0109",
+            DiagnosticCode::ExpectedEndOfLine => "B0110",
+            DiagnosticCode::UndefinedVariable => "B0201",
+            DiagnosticCode::UndefinedLabel => "B0202",
+            DiagnosticCode::UndefinedProcedure => "B0203",
+            DiagnosticCode::Redefinition => "B0204",
+            DiagnosticCode::ConflictingLinkage => "B0205",
+            DiagnosticCode::UnknownType => "B0206",
+            DiagnosticCode::TypeMismatch => "B0301",
+            DiagnosticCode::NonNumericOperand => "B0302",
+            DiagnosticCode::NonComparableOperand => "B0303",
+            DiagnosticCode::NonLogicalOperand => "B0304",
+            DiagnosticCode::OperandCount => "B0305",
+            DiagnosticCode::InvalidOperand => "B0306",
+            DiagnosticCode::ApiCall => "B0307",
+            DiagnosticCode::InvalidRegister => "B0308",
+            DiagnosticCode::DuplicateStatic => "B0401",
+            DiagnosticCode::DuplicateHandle => "B0402",
+            DiagnosticCode::UndefinedHandle => "B0403",
+            DiagnosticCode::UndefinedGlobal => "B0501",
+            DiagnosticCode::DuplicateGlobal => "B0502",
+            DiagnosticCode::UnresolvedExtern => "B0503",
+            DiagnosticCode::UnusedLabel => "B0601",
+            DiagnosticCode::ShadowedSymbol => "B0602",
+            DiagnosticCode::TruncatingImmediate => "B0603",
+            DiagnosticCode::UnreachableCode => "B0604",
+            DiagnosticCode::RedundantDeclaration => "B0605",
+            DiagnosticCode::ConfusableIdentifier => "B0606",
+            DiagnosticCode::Internal => "B0901",
+        }
+    }
+
+    // Büyük/küçük harf duyarsız: "b0202" de kabul edilir
+    pub fn from_code(code: &str) -> Option<DiagnosticCode> {
+        DiagnosticCode::ALL.iter().copied().find(|c| c.as_str().eq_ignore_ascii_case(code))
+    }
+
+    // Bu tanılamayı üreten en küçük örnek ve aynı örneğin düzeltilmiş hali
+    pub fn examples(&self) -> (&'static str, &'static str) {
+        match self {
+            DiagnosticCode::UnexpectedCharacter => ("ALLOCATE 16 AS BUF #", "ALLOCATE 16 AS BUF"),
+            DiagnosticCode::UnterminatedString => ("ACQUIRE \"konsol AS CONSOLE", "ACQUIRE \"konsol\" AS CONSOLE"),
+            DiagnosticCode::UnexpectedToken => ("ALLOCATE 1024 MEM1", "ALLOCATE 1024 AS MEM1"),
+            DiagnosticCode::ExpectedExpression => ("ALLOCATE 1024 AS MEM1\nRELEASE :", "ALLOCATE 1024 AS MEM1\nRELEASE MEM1"),
+            DiagnosticCode::MissingOperand => ("JUMP 5", "LOOP:\n    JUMP LOOP"),
+            DiagnosticCode::UnknownDirective => (".dta\nMSG: .string \"merhaba\"\n.code\nSEND 1, $MSG", ".data\nMSG: .string \"merhaba\"\n.code\nSEND 1, $MSG"),
+            DiagnosticCode::InvalidNumber => ("ALLOCATE 0x1G AS MEM1", "ALLOCATE 0x1F AS MEM1"),
+            DiagnosticCode::NumberOverflow => ("ALLOCATE 0x1_0000_0000_0000_0000 AS MEM1", "ALLOCATE 0xFFFF_FFFF AS MEM1"),
+            DiagnosticCode::InvalidEscape => ("ACQUIRE \"C:\\konsol\" AS CONSOLE", "ACQUIRE \"C:\\\\konsol\" AS CONSOLE"),
+            DiagnosticCode::ExpectedEndOfLine => ("SLEEP 10 YIELD", "SLEEP 10\nYIELD"),
+            DiagnosticCode::UndefinedVariable => ("WRITE COUNT", "VAR COUNT DWORD\nWRITE COUNT"),
+            DiagnosticCode::UndefinedLabel => ("JUMP NOWHERE", "JUMP DONE\nDONE:"),
+            DiagnosticCode::UndefinedProcedure => ("SPAWN WORKER", "SPAWN WORKER\nEXIT\nPROCEDURE_WORKER:\nEXIT"),
+            DiagnosticCode::Redefinition => ("START:\nSTART:", "START:\nJUMP RETRY\nRETRY:\nJUMP START"),
+            DiagnosticCode::ConflictingLinkage => ("GLOBAL START\nEXTERN START", "GLOBAL START\nSTART:"),
+            DiagnosticCode::UnknownType => ("VAR COUNT INT", "VAR COUNT DWORD"),
+            DiagnosticCode::TypeMismatch => ("RELEASE \"MEM1\"", "ALLOCATE 4 AS MEM1\nRELEASE MEM1"),
+            DiagnosticCode::NonNumericOperand => ("VAR NAME STRING\nADD NAME, 1", "VAR COUNT DWORD\nADD COUNT, 1"),
+            DiagnosticCode::NonComparableOperand => ("VAR NAME STRING\nCMP NAME, 10", "VAR COUNT DWORD\nCMP COUNT, 10"),
+            DiagnosticCode::NonLogicalOperand => ("VAR NAME STRING\nNOT NAME", "VAR MASK DWORD\nNOT MASK"),
+            DiagnosticCode::OperandCount => ("VAR COUNT", "VAR COUNT DWORD"),
+            DiagnosticCode::InvalidOperand => ("READ 5", "VAR INPUT DWORD\nREAD INPUT"),
+            DiagnosticCode::ApiCall => ("ACQUIRE \"sahne://console\" AS CONSOLE\nCTRL CONSOLE, \"RESET\"", "ACQUIRE \"sahne://console\" AS CONSOLE\nCTRL CONSOLE, 1"),
+            DiagnosticCode::InvalidRegister => ("RELEASE R8", "RELEASE R7"),
+            DiagnosticCode::DuplicateStatic => ("VAR COUNT DWORD\nPROCEDURE_WORK:\nVAR COUNT DWORD", "VAR COUNT DWORD\nPROCEDURE_WORK:\nVAR LOCAL_COUNT DWORD"),
+            DiagnosticCode::DuplicateHandle => ("ALLOCATE 4 AS MEM\nPROCEDURE_WORK:\nALLOCATE 8 AS MEM", "ALLOCATE 4 AS MEM\nPROCEDURE_WORK:\nALLOCATE 8 AS WORK_MEM"),
+            DiagnosticCode::UndefinedHandle => ("RELEASE MEM1", "ALLOCATE 1024 AS MEM1\nRELEASE MEM1"),
+            DiagnosticCode::UndefinedGlobal => ("GLOBAL START", "GLOBAL START\nSTART:"),
+            DiagnosticCode::DuplicateGlobal => ("; a.b64\nGLOBAL START\nSTART:\n; b.b64\nGLOBAL START\nSTART:", "; a.b64\nGLOBAL START\nSTART:\n; b.b64\nEXTERN START\nJUMP START"),
+            DiagnosticCode::UnresolvedExtern => ("; main.b64\nEXTERN WORKER\nJUMP WORKER", "; main.b64\nEXTERN WORKER\nJUMP WORKER\n; worker.b64\nGLOBAL WORKER\nWORKER:"),
+            DiagnosticCode::UnusedLabel => ("START:\nEND:\n    JUMP START", "START:\n    JUMP START"),
+            DiagnosticCode::ShadowedSymbol => ("VAR COUNT DWORD\nPROCEDURE_WORK:\nGET_TASK_ID COUNT", "VAR COUNT DWORD\nPROCEDURE_WORK:\nGET_TASK_ID TASK"),
+            DiagnosticCode::TruncatingImmediate => ("VAR SMALL BYTE\nSMALL = 300", "VAR SMALL WORD\nSMALL = 300"),
+            DiagnosticCode::UnreachableCode => ("JUMP DONE\nYIELD\nDONE:", "YIELD\nJUMP DONE\nDONE:"),
+            DiagnosticCode::RedundantDeclaration => ("GLOBAL START\nGLOBAL START\nSTART:", "GLOBAL START\nSTART:"),
+            DiagnosticCode::ConfusableIdentifier => ("VAR sayı DWORD\nVAR sayi DWORD", "VAR sayı DWORD\nVAR toplam DWORD"),
+            DiagnosticCode::Internal => ("", ""),
+        }
+    }
+
+    // `bessc --explain KOD` çıktısı; başlık ve açıklama mesaj kataloğundan gelir
+    pub fn explain(&self, language: Language) -> String {
+        let (title, description) = messages::explanation(language, *self);
+        let (failing, fixed) = self.examples();
+        let indent = |example: &str| example.lines().map(|line| format!("    {}\n", line)).collect::<String>();
+        let mut out = format!("{}: {}\n\n{}\n", self.as_str(), title, description);
+        if !failing.is_empty() {
+            out.push_str(&format!("\n{}\n\n{}", messages::lookup(language, "ui.failing-example"), indent(failing)));
+        }
+        if !fixed.is_empty() {
+            out.push_str(&format!("\n{}\n\n{}", messages::lookup(language, "ui.fixed-example"), indent(fixed)));
+        }
+        out
+    }
+}
+
+impl fmt::Display for DiagnosticCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::Compiler;
+    use crate::file_provider::SourceFile;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_codes_are_unique_and_explained() {
+        let codes: HashSet<&str> = DiagnosticCode::ALL.iter().map(|code| code.as_str()).collect();
+        assert_eq!(codes.len(), DiagnosticCode::ALL.len());
+        for code in DiagnosticCode::ALL {
+            assert_eq!(DiagnosticCode::from_code(&code.as_str().to_lowercase()), Some(code));
+            let (title, description) = messages::explanation(Language::default(), code);
+            assert!(!title.is_empty() && !description.is_empty(), "{}", code);
+            let (failing, fixed) = code.examples();
+            if code != DiagnosticCode::Internal {
+                assert!(!failing.is_empty() && !fixed.is_empty(), "{}", code);
+            }
+        }
+        assert!(DiagnosticCode::UndefinedLabel.explain(Language::Turkish).starts_with("B0202: Tanımsız etiket\n\n"));
+        assert!(DiagnosticCode::UndefinedLabel.explain(Language::English).contains("\nErroneous example:\n\n    JUMP NOWHERE\n"));
+    }
+
+    // "; ad.b64" satırlarıyla ayrılmış örnekleri ayrı kaynak dosyalarına böler
+    fn example_sources(example: &str) -> Vec<SourceFile> {
+        let mut sources: Vec<SourceFile> = Vec::new();
+        for line in example.lines() {
+            match line.strip_prefix("; ").filter(|name| name.ends_with(".b64")) {
+                Some(name) => sources.push(SourceFile::new(name, "")),
+                None => {
+                    if sources.is_empty() {
+                        sources.push(SourceFile::new("main.b64", ""));
+                    }
+                    let source = sources.last_mut().unwrap();
+                    source.text.push_str(line);
+                    source.text.push('\n');
+                }
+            }
+        }
+        sources
+    }
+
+    #[test]
+    fn test_examples_report_their_own_code() {
+        for code in DiagnosticCode::ALL.into_iter().filter(|code| *code != DiagnosticCode::Internal) {
+            let (failing, fixed) = code.examples();
+            let result = Compiler::new().compile_sources(&example_sources(failing));
+            assert!(result.diagnostics.iter().any(|d| d.code == code), "{}: {:?}", code, result.diagnostics);
+
+            let result = Compiler::new().compile_sources(&example_sources(fixed));
+            assert!(result.diagnostics.is_empty(), "{}: {:?}", code, result.diagnostics);
+        }
+    }
+}
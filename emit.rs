@@ -0,0 +1,446 @@
+use crate::compiler::{memory_map_line, CompileResult, UnitOutput};
+use crate::json::JsonValue;
+use crate::keywords::CaseSensitivity;
+use crate::lexer::{Lexer, Token, TokenType};
+use crate::memory_manager::{MemoryAllocation, MemorySection};
+use crate::messages::{msg, Language};
+use crate::parser::{AstNode, Expression};
+use crate::symbol_table::{Scope, Symbol, SymbolTable, SymbolType};
+
+// Derleme hattının ara çıktıları (--emit)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EmitKind {
+    Tokens,
+    Ast,
+    Symbols,
+    Memory, // Statik bellek ve handle yerleşimi
+    Code,   // Bağlama öncesi üretilen kod
+    Link,   // Bağlanmış çıktı
+}
+
+impl EmitKind {
+    pub const ALL: [EmitKind; 6] = [EmitKind::Tokens, EmitKind::Ast, EmitKind::Symbols, EmitKind::Memory, EmitKind::Code, EmitKind::Link];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EmitKind::Tokens => "tokens",
+            EmitKind::Ast => "ast",
+            EmitKind::Symbols => "symbols",
+            EmitKind::Memory => "memory",
+            EmitKind::Code => "code",
+            EmitKind::Link => "link",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<EmitKind> {
+        EmitKind::ALL.iter().copied().find(|kind| kind.name() == name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EmitFormat {
+    Text,
+    Json,
+}
+
+// İstenen ara çıktıları tek bir metin olarak döndürür. Metin biçiminde birden
+// fazla bölüm varsa her biri ";; <tür> [dosya]" başlığıyla ayrılır; JSON biçiminde
+// her tür, nesnenin bir alanıdır. Dil yalnızca metin biçimindeki açıklamaları etkiler.
+pub fn render(kinds: &[EmitKind], format: EmitFormat, result: &CompileResult, language: Language) -> String {
+    match format {
+        EmitFormat::Text => render_text(kinds, result, language),
+        EmitFormat::Json => {
+            let fields = kinds.iter().map(|kind| (kind.name(), emit_json(*kind, result))).collect();
+            format!("{}\n", JsonValue::object(fields))
+        }
+    }
+}
+
+fn render_text(kinds: &[EmitKind], result: &CompileResult, language: Language) -> String {
+    let mut output = String::new();
+    for kind in kinds {
+        let sections: Vec<(Option<&str>, Option<Vec<String>>)> = match kind {
+            EmitKind::Tokens => result.sources.iter().map(|s| (Some(s.name.as_str()), Some(tokens_text(&s.text, result.case_sensitivity)))).collect(),
+            EmitKind::Link => vec![(None, result.artifacts.object.clone())],
+            _ => result.units.iter().map(|unit| (Some(unit.name.as_str()), unit_text(*kind, unit))).collect(),
+        };
+        let with_headers = kinds.len() > 1 || sections.len() > 1;
+        for (file, lines) in sections {
+            if with_headers {
+                match file {
+                    Some(file) => output.push_str(&format!(";; {} {}\n", kind.name(), file)),
+                    None => output.push_str(&format!(";; {}\n", kind.name())),
+                }
+            }
+            // Henüz üretilmemiş (önceki bir aşama başarısız olmuş) bölümler
+            let unavailable = || vec![format!("; {}", msg!("emit.unavailable").text(language))];
+            for line in lines.unwrap_or_else(unavailable) {
+                output.push_str(&line);
+                output.push('\n');
+            }
+        }
+    }
+    output
+}
+
+fn unit_text(kind: EmitKind, unit: &UnitOutput) -> Option<Vec<String>> {
+    match kind {
+        EmitKind::Ast => Some(ast_text(&unit.ast)),
+        EmitKind::Symbols => unit.symbol_table.as_ref().map(symbols_text),
+        EmitKind::Memory => unit
+            .memory
+            .as_ref()
+            .map(|memory| memory.iter().map(|(name, allocation)| memory_map_line(name, allocation)).collect()),
+        EmitKind::Code => unit.object.as_ref().map(|object| object.code.clone()),
+        EmitKind::Tokens | EmitKind::Link => None,
+    }
+}
+
+fn emit_json(kind: EmitKind, result: &CompileResult) -> JsonValue {
+    let per_file = |name: &str, key: &str, value: JsonValue| JsonValue::object(vec![("file", JsonValue::string(name)), (key, value)]);
+    match kind {
+        EmitKind::Tokens => JsonValue::Array(
+            result
+                .sources
+                .iter()
+                .map(|s| per_file(&s.name, "tokens", JsonValue::Array(lex_all(&s.text, result.case_sensitivity).iter().map(token_json).collect())))
+                .collect(),
+        ),
+        EmitKind::Link => result.artifacts.object.as_ref().map(|object| JsonValue::strings(object)).into(),
+        EmitKind::Ast => JsonValue::Array(result.units.iter().map(|u| per_file(&u.name, "ast", ast_json(&u.ast))).collect()),
+        EmitKind::Symbols => JsonValue::Array(
+            result
+                .units
+                .iter()
+                .map(|u| per_file(&u.name, "symbols", u.symbol_table.as_ref().map(symbols_json).into()))
+                .collect(),
+        ),
+        EmitKind::Memory => JsonValue::Array(
+            result
+                .units
+                .iter()
+                .map(|u| {
+                    let memory = u.memory.as_ref().map(|memory| {
+                        JsonValue::Array(memory.iter().map(|(name, allocation)| allocation_json(name, allocation)).collect())
+                    });
+                    per_file(&u.name, "memory", memory.into())
+                })
+                .collect(),
+        ),
+        EmitKind::Code => JsonValue::Array(
+            result
+                .units
+                .iter()
+                .map(|u| per_file(&u.name, "code", u.object.as_ref().map(|object| JsonValue::strings(&object.code)).into()))
+                .collect(),
+        ),
+    }
+}
+
+// ---- Token'lar ----
+
+fn lex_all(text: &str, case_sensitivity: CaseSensitivity) -> Vec<Token> {
+    let mut lexer = Lexer::new(text.to_string());
+    lexer.set_case_sensitivity(case_sensitivity);
+    lexer.collect()
+}
+
+// Token türünün adı ve (varsa) değeri
+fn token_parts(token_type: &TokenType) -> (&'static str, Option<String>) {
+    match token_type {
+        TokenType::Keyword(value) => ("Keyword", Some(value.clone())),
+        TokenType::Mnemonic(value) => ("Mnemonic", Some(value.clone())),
+        TokenType::Identifier(value) => ("Identifier", Some(value.clone())),
+        TokenType::Number(value) => ("Number", Some(value.to_string())),
+        TokenType::Flag(value) => ("Flag", Some(value.clone())),
+        TokenType::InvalidNumber(text, _) => ("InvalidNumber", Some(text.clone())),
+        TokenType::Handle(value) => ("Handle", Some(value.to_string())),
+        TokenType::TaskId(value) => ("TaskId", Some(value.to_string())),
+        TokenType::ResourceId(value) => ("ResourceId", Some(value.clone())),
+        TokenType::Register(index) => ("Register", Some(index.to_string())),
+        TokenType::Colon => ("Colon", None),
+        TokenType::Comma => ("Comma", None),
+        TokenType::OpenParen => ("OpenParen", None),
+        TokenType::CloseParen => ("CloseParen", None),
+        TokenType::OpenBracket => ("OpenBracket", None),
+        TokenType::CloseBracket => ("CloseBracket", None),
+        TokenType::Equals => ("Equals", None),
+        TokenType::Plus => ("Plus", None),
+        TokenType::Minus => ("Minus", None),
+        TokenType::Dollar => ("Dollar", None),
+        TokenType::Directive(value) => ("Directive", Some(value.clone())),
+        TokenType::LocationCounter => ("LocationCounter", None),
+        TokenType::StringLiteral(value) => ("StringLiteral", Some(format!("{:?}", value))),
+        TokenType::InvalidString(value, _) => ("InvalidString", Some(format!("{:?}", value))),
+        TokenType::UnterminatedString(value) => ("UnterminatedString", Some(format!("{:?}", value))),
+        TokenType::Comment(value) => ("Comment", Some(format!("{:?}", value))),
+        TokenType::Newline => ("Newline", None),
+        TokenType::EndOfFile => ("EndOfFile", None),
+        TokenType::Unknown(value) => ("Unknown", Some(format!("{:?}", value))),
+    }
+}
+
+fn tokens_text(text: &str, case_sensitivity: CaseSensitivity) -> Vec<String> {
+    lex_all(text, case_sensitivity)
+        .iter()
+        .map(|token| match token_parts(&token.token_type) {
+            (kind, Some(value)) => format!("{}:{} {} {}", token.line, token.column, kind, value),
+            (kind, None) => format!("{}:{} {}", token.line, token.column, kind),
+        })
+        .collect()
+}
+
+fn token_json(token: &Token) -> JsonValue {
+    let (kind, value) = token_parts(&token.token_type);
+    let value = match &token.token_type {
+        TokenType::Number(n) => Some(JsonValue::Number(*n)),
+        TokenType::Handle(n) | TokenType::TaskId(n) | TokenType::Register(n) => Some(JsonValue::Number(*n as i64)),
+        TokenType::StringLiteral(s) | TokenType::InvalidString(s, _) | TokenType::UnterminatedString(s) | TokenType::Comment(s) => Some(JsonValue::string(s)),
+        TokenType::Unknown(c) => Some(JsonValue::String(c.to_string())),
+        _ => value.map(JsonValue::String),
+    };
+    JsonValue::object(vec![
+        ("kind", JsonValue::string(kind)),
+        ("value", value.into()),
+        ("line", JsonValue::Number(token.line as i64)),
+        ("column", JsonValue::Number(token.column as i64)),
+        ("start", JsonValue::Number(token.span.start as i64)),
+        ("end", JsonValue::Number(token.span.end as i64)),
+    ])
+}
+
+// ---- AST ----
+
+enum Field<'a> {
+    Name(&'a str),
+    Expr(&'a Expression),
+    OptionalExpr(Option<&'a Expression>),
+    Exprs(&'a [Expression]),
+}
+
+// Düğüm türünün adı ve alanları (Program hariç)
+fn node_fields(node: &AstNode) -> (&'static str, Vec<(&'static str, Field<'_>)>) {
+    match node {
+        AstNode::JumpStatement { target } => ("JumpStatement", vec![("target", Field::Name(target))]),
+        AstNode::FlagDeclaration { flag } => ("FlagDeclaration", vec![("flag", Field::Name(flag))]),
+        AstNode::Assignment { variable, value } => ("Assignment", vec![("variable", Field::Name(variable)), ("value", Field::Expr(value))]),
+        AstNode::AllocateMemory { size, handle } => ("AllocateMemory", vec![("size", Field::Expr(size)), ("handle", Field::Name(handle))]),
+        AstNode::ReleaseMemory { handle } => ("ReleaseMemory", vec![("handle", Field::Expr(handle))]),
+        AstNode::SpawnTask { function, priority } => {
+            ("SpawnTask", vec![("function", Field::Name(function)), ("priority", Field::OptionalExpr(priority.as_ref()))])
+        }
+        AstNode::ExitTask { code } => ("ExitTask", vec![("code", Field::OptionalExpr(code.as_ref()))]),
+        AstNode::SleepTask { duration } => ("SleepTask", vec![("duration", Field::Expr(duration))]),
+        AstNode::YieldTask => ("YieldTask", vec![]),
+        AstNode::AcquireResource { name, handle } => ("AcquireResource", vec![("name", Field::Expr(name)), ("handle", Field::Name(handle))]),
+        AstNode::ControlResource { handle, command } => ("ControlResource", vec![("handle", Field::Expr(handle)), ("command", Field::Expr(command))]),
+        AstNode::SendMessage { handle, message } => ("SendMessage", vec![("handle", Field::Expr(handle)), ("message", Field::Expr(message))]),
+        AstNode::ReceiveMessage { handle, buffer } => ("ReceiveMessage", vec![("handle", Field::Expr(handle)), ("buffer", Field::Name(buffer))]),
+        AstNode::GetTaskId { target } => ("GetTaskId", vec![("target", Field::Name(target))]),
+        AstNode::GetCoreId { target } => ("GetCoreId", vec![("target", Field::Name(target))]),
+        AstNode::GetTotalCores { target } => ("GetTotalCores", vec![("target", Field::Name(target))]),
+        AstNode::Instruction { opcode, operands } => ("Instruction", vec![("opcode", Field::Name(opcode)), ("operands", Field::Exprs(operands))]),
+        AstNode::Label { name } => ("Label", vec![("name", Field::Name(name))]),
+        AstNode::Section { section } => ("Section", vec![("section", Field::Name(section.name()))]),
+        AstNode::DataDirective { kind, values } => ("DataDirective", vec![("kind", Field::Name(kind.name())), ("values", Field::Exprs(values))]),
+        AstNode::Constant { name, value } => ("Constant", vec![("name", Field::Name(name)), ("value", Field::Expr(value))]),
+        AstNode::Program(_) => ("Program", vec![]),
+    }
+}
+
+fn expression_parts(expression: &Expression) -> (&'static str, String) {
+    match expression {
+        Expression::Identifier(name) => ("Identifier", name.clone()),
+        Expression::Number(value) => ("Number", value.to_string()),
+        Expression::Flag(flag) => ("Flag", flag.clone()),
+        Expression::StringLiteral(value) => ("String", format!("{:?}", value)),
+        Expression::Handle(value) => ("Handle", value.to_string()),
+        Expression::TaskId(value) => ("TaskId", value.to_string()),
+        Expression::Register(index) => ("Register", index.to_string()),
+        Expression::AddressOf(name) => ("AddressOf", name.clone()),
+        Expression::LocationCounter => ("LocationCounter", ".".to_string()),
+        Expression::Memory(address) => ("Memory", expression_text(address)),
+        Expression::Binary { operator, left, right } => {
+            ("Binary", format!("{} {} {}", expression_text(left), operator.symbol(), expression_text(right)))
+        }
+    }
+}
+
+fn expression_text(expression: &Expression) -> String {
+    let (kind, value) = expression_parts(expression);
+    format!("{}({})", kind, value)
+}
+
+fn ast_text(ast: &AstNode) -> Vec<String> {
+    let statements = match ast {
+        AstNode::Program(statements) => statements.as_slice(),
+        _ => &[],
+    };
+    let mut lines = vec!["Program".to_string()];
+    for statement in statements {
+        let (kind, fields) = node_fields(&statement.node);
+        let mut line = format!("  {}", kind);
+        for (name, field) in fields {
+            let value = match field {
+                Field::Name(value) => value.to_string(),
+                Field::Expr(expression) => expression_text(expression),
+                Field::OptionalExpr(expression) => expression.map_or("-".to_string(), expression_text),
+                Field::Exprs(expressions) => format!("[{}]", expressions.iter().map(expression_text).collect::<Vec<_>>().join(", ")),
+            };
+            line.push_str(&format!(" {}={}", name, value));
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+fn expression_json(expression: &Expression) -> JsonValue {
+    let value = match expression {
+        Expression::Number(value) => JsonValue::Number(*value),
+        Expression::Handle(value) | Expression::TaskId(value) | Expression::Register(value) => JsonValue::Number(*value as i64),
+        Expression::Identifier(value) | Expression::Flag(value) | Expression::StringLiteral(value) | Expression::AddressOf(value) => {
+            JsonValue::string(value)
+        }
+        Expression::LocationCounter => JsonValue::Null,
+        Expression::Memory(address) => expression_json(address),
+        Expression::Binary { operator, left, right } => {
+            return JsonValue::object(vec![
+                ("kind", JsonValue::string("Binary")),
+                ("operator", JsonValue::string(operator.symbol())),
+                ("left", expression_json(left)),
+                ("right", expression_json(right)),
+            ]);
+        }
+    };
+    JsonValue::object(vec![("kind", JsonValue::string(expression_parts(expression).0)), ("value", value)])
+}
+
+fn ast_json(ast: &AstNode) -> JsonValue {
+    if let AstNode::Program(statements) = ast {
+        return JsonValue::object(vec![
+            ("kind", JsonValue::string("Program")),
+            ("statements", JsonValue::Array(statements.iter().map(|statement| ast_json(&statement.node)).collect())),
+        ]);
+    }
+    let (kind, fields) = node_fields(ast);
+    let mut object = vec![("kind", JsonValue::string(kind))];
+    for (name, field) in fields {
+        let value = match field {
+            Field::Name(value) => JsonValue::string(value),
+            Field::Expr(expression) => expression_json(expression),
+            Field::OptionalExpr(expression) => expression.map(expression_json).into(),
+            Field::Exprs(expressions) => JsonValue::Array(expressions.iter().map(expression_json).collect()),
+        };
+        object.push((name, value));
+    }
+    JsonValue::object(object)
+}
+
+// ---- Semboller ----
+
+fn symbol_kind(symbol_type: &SymbolType) -> String {
+    match symbol_type {
+        SymbolType::Variable(Some(var_type)) => format!("variable {}", var_type),
+        SymbolType::Variable(None) => "variable".to_string(),
+        SymbolType::Label => "label".to_string(),
+        SymbolType::Constant => "constant".to_string(),
+        SymbolType::Procedure => "procedure".to_string(),
+        SymbolType::Macro => "macro".to_string(),
+        SymbolType::External => "external".to_string(),
+        SymbolType::Global => "global".to_string(),
+        SymbolType::Handle => "handle".to_string(),
+        SymbolType::TaskId => "task_id".to_string(),
+        SymbolType::ResourceId => "resource_id".to_string(),
+    }
+}
+
+fn scope_name(scope: &Scope) -> String {
+    match scope {
+        Scope::Global => "global".to_string(),
+        Scope::Local(procedure) => format!("local({})", procedure),
+    }
+}
+
+fn symbols_text(symbol_table: &SymbolTable) -> Vec<String> {
+    symbol_table
+        .symbols()
+        .iter()
+        .map(|symbol| format!("{:<24} {:<16} {}", symbol.name, symbol_kind(&symbol.symbol_type), scope_name(&symbol.scope)))
+        .collect()
+}
+
+fn symbol_json(symbol: &Symbol) -> JsonValue {
+    JsonValue::object(vec![
+        ("name", JsonValue::string(&symbol.name)),
+        ("kind", JsonValue::String(symbol_kind(&symbol.symbol_type))),
+        ("scope", JsonValue::String(scope_name(&symbol.scope))),
+    ])
+}
+
+fn symbols_json(symbol_table: &SymbolTable) -> JsonValue {
+    JsonValue::Array(symbol_table.symbols().into_iter().map(symbol_json).collect())
+}
+
+// ---- Bellek yerleşimi ----
+
+fn allocation_json(name: &str, allocation: &MemoryAllocation) -> JsonValue {
+    let (section, address) = match allocation.section {
+        MemorySection::Static => ("static", JsonValue::Number(allocation.address as i64)),
+        MemorySection::Data => ("data", JsonValue::Number(allocation.address as i64)),
+        MemorySection::Handle => ("handle", JsonValue::Null),
+    };
+    JsonValue::object(vec![
+        ("name", JsonValue::string(name)),
+        ("section", JsonValue::string(section)),
+        ("address", address),
+        ("size", JsonValue::Number(allocation.size as i64)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::Compiler;
+    use crate::file_provider::SourceFile;
+
+    fn compile(text: &str) -> CompileResult {
+        Compiler::new().compile_sources(&[SourceFile::new("main.b64", text)])
+    }
+
+    #[test]
+    fn test_emit_text_stages() {
+        let result = compile("GLOBAL START\nSTART:\nRELEASE 5");
+
+        assert_eq!(
+            render(&[EmitKind::Tokens], EmitFormat::Text, &result, Language::Turkish),
+            "1:1 Keyword GLOBAL\n1:8 Identifier START\n2:1 Identifier START\n2:6 Colon\n3:1 Keyword RELEASE\n3:9 Number 5\n3:10 EndOfFile\n"
+        );
+        assert_eq!(
+            render(&[EmitKind::Ast], EmitFormat::Text, &result, Language::Turkish),
+            "Program\n  Instruction opcode=GLOBAL operands=[Identifier(START)]\n  Label name=START\n  ReleaseMemory handle=Number(5)\n"
+        );
+        assert_eq!(
+            render(&[EmitKind::Code, EmitKind::Link], EmitFormat::Text, &result, Language::Turkish),
+            ";; code main.b64\nSTART:\nSYS_CALL 2\nARG 5\n;; link\nSTART:\nSYS_CALL 2\nARG 5\n"
+        );
+        assert!(render(&[EmitKind::Symbols], EmitFormat::Text, &result, Language::Turkish).starts_with("START "));
+    }
+
+    #[test]
+    fn test_emit_json_and_unavailable_stages() {
+        let result = compile("EXTERN MISSING\nJUMP MISSING");
+        assert!(!result.is_success());
+
+        assert_eq!(render(&[EmitKind::Link], EmitFormat::Text, &result, Language::Turkish), "; kullanılamıyor (önceki bir aşama başarısız oldu)\n");
+        assert_eq!(render(&[EmitKind::Link], EmitFormat::Text, &result, Language::English), "; unavailable (an earlier stage failed)\n");
+        assert_eq!(
+            render(&[EmitKind::Ast, EmitKind::Link], EmitFormat::Json, &result, Language::Turkish),
+            concat!(
+                r#"{"ast":[{"file":"main.b64","ast":{"kind":"Program","statements":["#,
+                r#"{"kind":"Instruction","opcode":"EXTERN","operands":[{"kind":"Identifier","value":"MISSING"}]},"#,
+                r#"{"kind":"JumpStatement","target":"MISSING"}]}}],"link":null}"#,
+                "\n"
+            )
+        );
+    }
+}
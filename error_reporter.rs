@@ -1,39 +1,483 @@
-use crate::lexer::Token;
-
-pub struct ErrorReporter {
-    errors: Vec<String>,
-}
-
-impl ErrorReporter {
-    pub fn new() -> Self {
-        ErrorReporter { errors: Vec::new() }
-    }
-
-    pub fn report_error(&mut self, message: String, token: Option<&Token>) {
-        let location = match token {
-            Some(t) => format!("(Satır: {}, Sütun: {})", t.line, t.column),
-            None => "(Bilinmeyen konum)".to_string(),
-        };
-        self.errors.push(format!("Hata {}: {}", location, message));
-    }
-
-    pub fn has_errors(&self) -> bool {
-        !self.errors.is_empty()
-    }
-
-    pub fn print_errors(&self) {
-        for error in &self.errors {
-            eprintln!("{}", error);
-        }
-    }
-
-    // Sahne64 API'sine özgü hata mesajları için yardımcı fonksiyonlar eklenebilir
-    pub fn report_sahne64_api_error(&mut self, api_call: &str, message: String, token: Option<&Token>) {
-        self.report_error(format!("Sahne64 API Hatası ({}: {}): {}", api_call, message, token.map(|t| format!("(Satır: {}, Sütun: {})", t.line, t.column)).unwrap_or_else(|| "(Bilinmeyen konum)".to_string())), token);
-    }
-
-    // İleride uyarılar için de benzer bir mekanizma eklenebilir
-     warnings: Vec<String>,
-     pub fn report_warning(...)
-     pub fn print_warnings(...)
-}
\ No newline at end of file
+use crate::diagnostic_code::DiagnosticCode;
+use crate::json::JsonValue;
+use crate::lexer::{SourceLocation, Token};
+use crate::messages::{self, msg, Language, Message};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+// Uyarı kategorileri; komut satırından tek tek kapatılabilir (-A), hataya
+// dönüştürülebilir (-D) veya yeniden açılabilir (-W)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    UnusedLabel,          // Hiçbir yerden atlanmayan etiket
+    ShadowedSymbol,       // Dış kapsamdaki bir sembolü gölgeleyen tanım
+    TruncatingImmediate,  // Hedef tipe sığmayan sayısal sabit
+    UnreachableCode,      // Koşulsuz JUMP veya EXIT'ten sonra gelen, etiketsiz kod
+    RedundantDeclaration, // Aynı sembol için tekrarlanan GLOBAL/EXTERN
+    ConfusableIdentifier, // Başka bir adla ekranda aynı görünen ad (ör. "sayı" ve "sayi")
+}
+
+impl Lint {
+    pub const ALL: [Lint; 6] = [
+        Lint::UnusedLabel,
+        Lint::ShadowedSymbol,
+        Lint::TruncatingImmediate,
+        Lint::UnreachableCode,
+        Lint::RedundantDeclaration,
+        Lint::ConfusableIdentifier,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::UnusedLabel => "unused-label",
+            Lint::ShadowedSymbol => "shadowed-symbol",
+            Lint::TruncatingImmediate => "truncating-immediate",
+            Lint::UnreachableCode => "unreachable-code",
+            Lint::RedundantDeclaration => "redundant-declaration",
+            Lint::ConfusableIdentifier => "confusable-identifier",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Lint> {
+        Lint::ALL.iter().copied().find(|lint| lint.name() == name)
+    }
+
+    pub fn code(&self) -> DiagnosticCode {
+        match self {
+            Lint::UnusedLabel => DiagnosticCode::UnusedLabel,
+            Lint::ShadowedSymbol => DiagnosticCode::ShadowedSymbol,
+            Lint::TruncatingImmediate => DiagnosticCode::TruncatingImmediate,
+            Lint::UnreachableCode => DiagnosticCode::UnreachableCode,
+            Lint::RedundantDeclaration => DiagnosticCode::RedundantDeclaration,
+            Lint::ConfusableIdentifier => DiagnosticCode::ConfusableIdentifier,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LintLevel {
+    Allow, // Raporlanmaz
+    Warn,  // Uyarı olarak raporlanır (varsayılan)
+    Deny,  // Hata olarak raporlanır ve derlemeyi başarısız kılar
+}
+
+// Tanılamaların çıktı biçimi: insanlar için kaynak satırlı metin ya da
+// araçlar (CI, editör eklentileri) için satır başına bir JSON nesnesi
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MessageFormat {
+    Human,
+    Json,
+}
+
+// Tanılamanın ana konumu dışında işaret edilen bir konum (ör. "ilk tanım burada")
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    pub location: SourceLocation,
+    pub message: Message,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: DiagnosticCode,
+    pub message: Message,
+    pub file: Option<String>,
+    pub location: Option<SourceLocation>,
+    pub labels: Vec<Label>,
+    pub notes: Vec<Message>,
+    pub help: Option<Message>,
+    pub lint: Option<Lint>, // Uyarı kategorisi (hataya dönüştürülmüş olsa da korunur)
+}
+
+impl Diagnostic {
+    pub fn error(code: DiagnosticCode, message: Message, location: Option<SourceLocation>) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            code,
+            message,
+            file: None,
+            location,
+            labels: Vec::new(),
+            notes: Vec::new(),
+            help: None,
+            lint: None,
+        }
+    }
+
+    pub fn warning(lint: Lint, message: Message, location: Option<SourceLocation>) -> Self {
+        Diagnostic { severity: Severity::Warning, lint: Some(lint), ..Diagnostic::error(lint.code(), message, location) }
+    }
+
+    pub fn with_label(mut self, location: SourceLocation, message: Message) -> Self {
+        self.labels.push(Label { location, message });
+        self
+    }
+
+    pub fn with_note(mut self, note: Message) -> Self {
+        self.notes.push(note);
+        self
+    }
+
+    pub fn with_help(mut self, help: Message) -> Self {
+        self.help = Some(help);
+        self
+    }
+
+    // rustc tarzı çok satırlı gösterim: başlık, "--> dosya:satır:sütun", ilgili kaynak
+    // satırları, ana konumun altında ^^^, ikincil etiketlerin altında --- ve not/yardım
+    // satırları. source verilmezse yalnızca başlık ve konum yazılır.
+    pub fn render(&self, source: Option<&str>, color: bool, language: Language) -> String {
+        let paint = |style: &str, text: &str| if color { format!("\x1b[{}m{}\x1b[0m", style, text) } else { text.to_string() };
+        let (kind, kind_style) = match self.severity {
+            Severity::Error => (messages::lookup(language, "ui.error"), "1;31"),
+            Severity::Warning => (messages::lookup(language, "ui.warning"), "1;33"),
+        };
+        let header = format!("{}[{}]", kind, self.code);
+        let mut out = format!("{}{}\n", paint(kind_style, &header), paint("1", &format!(": {}", self.message.text(language))));
+
+        // Ana konum (^) ve etiketler (-) satır sırasına göre
+        let mut marks: Vec<(SourceLocation, char, String)> = self.location.iter().map(|location| (*location, '^', String::new())).collect();
+        marks.extend(self.labels.iter().map(|label| (label.location, '-', label.message.text(language))));
+        marks.sort_by_key(|(location, _, _)| (location.line, location.column));
+        let gutter = " ".repeat(marks.iter().map(|(l, _, _)| l.line.to_string().len()).max().unwrap_or(1));
+
+        let place = match (&self.file, self.location) {
+            (Some(file), Some(location)) => Some(format!("{}:{}:{}", file, location.line, location.column)),
+            (Some(file), None) => Some(file.clone()),
+            (None, Some(location)) => Some(format!("{}:{}", location.line, location.column)),
+            (None, None) => None,
+        };
+        if let Some(place) = place {
+            out.push_str(&format!("{}{} {}\n", gutter, paint("1;34", "-->"), place));
+        }
+
+        let bar = paint("1;34", "|");
+        let lines: Vec<&str> = source.map(|text| text.lines().collect()).unwrap_or_default();
+        let marks: Vec<_> = marks.into_iter().filter(|(location, _, _)| location.line >= 1 && location.line <= lines.len()).collect();
+        if !marks.is_empty() {
+            out.push_str(&format!("{} {}\n", gutter, bar));
+            let mut previous_line = None;
+            for (location, mark, label) in &marks {
+                // Aradaki satırlar gösterilmez; atlandıkları "..." ile belirtilir
+                if previous_line.is_some_and(|previous| location.line > previous + 1) {
+                    out.push_str(&format!("{}\n", paint("1;34", "...")));
+                }
+                previous_line = Some(location.line);
+                let line = lines[location.line - 1];
+                let number = format!("{:>width$}", location.line, width = gutter.len());
+                out.push_str(&format!("{} {} {}\n", paint("1;34", &number), bar, expand_tabs(line)));
+                let padding = display_width(line.chars().take(location.column.saturating_sub(1)));
+                let underline = mark.to_string().repeat(location.length.max(1));
+                let style = if *mark == '^' { kind_style } else { "1;34" };
+                let label = if label.is_empty() { String::new() } else { format!(" {}", label) };
+                out.push_str(&format!("{} {} {}{}\n", gutter, bar, " ".repeat(padding), paint(style, &format!("{}{}", underline, label))));
+            }
+        }
+
+        let lint_note = match (self.severity, self.lint) {
+            (Severity::Warning, Some(lint)) => Some(msg!("note.lint", lint.name())),
+            _ => None,
+        };
+        let (note, help) = (messages::lookup(language, "ui.note"), messages::lookup(language, "ui.help"));
+        let extras: Vec<(&str, String)> = lint_note
+            .iter()
+            .chain(&self.notes)
+            .map(|message| (note, message.text(language)))
+            .chain(self.help.iter().map(|message| (help, message.text(language))))
+            .collect();
+        if !extras.is_empty() {
+            if !marks.is_empty() {
+                out.push_str(&format!("{} {}\n", gutter, bar));
+            }
+            for (kind, text) in extras {
+                out.push_str(&format!("{} {} {}: {}\n", gutter, paint("1;34", "="), paint("1", kind), text));
+            }
+        }
+        out
+    }
+}
+
+impl Diagnostic {
+    // {"severity","code","message","file","line_start","column_start","line_end","column_end","related","notes","help"}
+    // Sütunlar 1'den başlar; column_end, işaretlenen aralığın hemen sonrasını gösterir.
+    pub fn to_json(&self, language: Language) -> JsonValue {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        let mut fields = vec![
+            ("severity", JsonValue::string(severity)),
+            ("code", JsonValue::string(self.code.as_str())),
+            ("lint", self.lint.map(|lint| JsonValue::string(lint.name())).into()),
+            ("message", JsonValue::String(self.message.text(language))),
+            ("file", self.file.as_deref().map(JsonValue::string).into()),
+        ];
+        fields.extend(location_fields(self.location));
+        let related = self.labels.iter().map(|label| {
+            let mut related = vec![("file", self.file.as_deref().map(JsonValue::string).into())];
+            related.extend(location_fields(Some(label.location)));
+            related.push(("message", JsonValue::String(label.message.text(language))));
+            JsonValue::object(related)
+        });
+        fields.push(("related", JsonValue::Array(related.collect())));
+        let notes: Vec<String> = self.notes.iter().map(|note| note.text(language)).collect();
+        fields.push(("notes", JsonValue::strings(&notes)));
+        fields.push(("help", self.help.as_ref().map(|help| JsonValue::String(help.text(language))).into()));
+        JsonValue::object(fields)
+    }
+
+    pub fn format(&self, format: MessageFormat, source: Option<&str>, color: bool, language: Language) -> String {
+        match format {
+            MessageFormat::Human => self.render(source, color, language),
+            MessageFormat::Json => format!("{}\n", self.to_json(language)),
+        }
+    }
+
+    // Tek satırlık özet: "dosya: Hata (Satır: x, Sütun: y): mesaj"
+    pub fn summary(&self, language: Language) -> String {
+        let kind = match self.severity {
+            Severity::Error => msg!("ui.error-summary"),
+            Severity::Warning => msg!("ui.warning-summary"),
+        };
+        let file = self.file.as_ref().map(|file| format!("{}: ", file)).unwrap_or_default();
+        let summary = match self.location {
+            Some(location) => msg!("ui.summary", kind.text(language), location.line, location.column, self.message.text(language)),
+            None => msg!("ui.summary-without-location", kind.text(language), self.message.text(language)),
+        };
+        format!("{}{}", file, summary.text(language))
+    }
+}
+
+fn location_fields(location: Option<SourceLocation>) -> Vec<(&'static str, JsonValue)> {
+    let number = |value: Option<usize>| value.map(|v| JsonValue::Number(v as i64)).into();
+    vec![
+        ("line_start", number(location.map(|l| l.line))),
+        ("column_start", number(location.map(|l| l.column))),
+        ("line_end", number(location.map(|l| l.line))),
+        ("column_end", number(location.map(|l| l.column + l.length))),
+    ]
+}
+
+// Sekmeler terminalde farklı genişlikte görünebilir; alt çizginin hizalı kalması için boşluğa çevrilir
+fn expand_tabs(line: &str) -> String {
+    line.replace('\t', "    ")
+}
+
+fn display_width(chars: impl Iterator<Item = char>) -> usize {
+    chars.map(|c| if c == '\t' { 4 } else { 1 }).sum()
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.summary(Language::default()))
+    }
+}
+
+pub struct ErrorReporter {
+    diagnostics: Vec<Diagnostic>,
+    current_file: Option<String>, // Raporlanan tanılamaların ait olduğu kaynak dosya
+    lint_levels: HashMap<Lint, LintLevel>, // Belirtilmeyen kategoriler Warn
+    warnings_as_errors: bool,              // -Werror
+}
+
+impl Default for ErrorReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ErrorReporter {
+    pub fn new() -> Self {
+        ErrorReporter {
+            diagnostics: Vec::new(),
+            current_file: None,
+            lint_levels: HashMap::new(),
+            warnings_as_errors: false,
+        }
+    }
+
+    pub fn set_lint_level(&mut self, lint: Lint, level: LintLevel) {
+        self.lint_levels.insert(lint, level);
+    }
+
+    pub fn set_warnings_as_errors(&mut self, enabled: bool) {
+        self.warnings_as_errors = enabled;
+    }
+
+    pub fn lint_level(&self, lint: Lint) -> LintLevel {
+        self.lint_levels.get(&lint).copied().unwrap_or(LintLevel::Warn)
+    }
+
+    pub fn set_file(&mut self, name: &str) {
+        self.current_file = Some(name.to_string());
+    }
+
+    pub fn report_error(&mut self, code: DiagnosticCode, message: Message, token: Option<&Token>) {
+        self.report_error_at(code, message, token.map(Token::location));
+    }
+
+    pub fn report_error_at(&mut self, code: DiagnosticCode, message: Message, location: Option<SourceLocation>) {
+        self.report(Diagnostic::error(code, message, location));
+    }
+
+    pub fn report_warning(&mut self, lint: Lint, message: Message, location: Option<SourceLocation>) {
+        self.report(Diagnostic::warning(lint, message, location));
+    }
+
+    // Etiket, not veya yardım satırı içeren tanılamalar için
+    pub fn report(&mut self, diagnostic: Diagnostic) {
+        // Aynı ifade birden fazla analiz geçişinde denetlenebilir; her tanılama bir kez raporlanır
+        if let Some(diagnostic) = self.prepare(diagnostic) {
+            if !self.diagnostics.contains(&diagnostic) {
+                self.diagnostics.push(diagnostic);
+            }
+        }
+    }
+
+    // Başka bir bileşenin (parser, semantik analizci) topladığı tanılamaları
+    // konum sırasına göre ekler; dosyası belirtilmemiş olanlar geçerli dosyaya bağlanır
+    pub fn extend(&mut self, mut diagnostics: Vec<Diagnostic>) {
+        diagnostics.sort_by_key(|d| d.location.map(|l| (l.line, l.column)));
+        for diagnostic in diagnostics {
+            if let Some(diagnostic) = self.prepare(diagnostic) {
+                self.diagnostics.push(diagnostic);
+            }
+        }
+    }
+
+    // Dosya adını doldurur ve uyarılara kategori düzeylerini uygular;
+    // kapatılmış (Allow) uyarılar için None döner
+    fn prepare(&self, mut diagnostic: Diagnostic) -> Option<Diagnostic> {
+        if diagnostic.file.is_none() {
+            diagnostic.file = self.current_file.clone();
+        }
+        let lint = match (diagnostic.severity, diagnostic.lint) {
+            (Severity::Warning, Some(lint)) => lint,
+            _ => return Some(diagnostic),
+        };
+        match self.lint_level(lint) {
+            LintLevel::Allow => return None,
+            LintLevel::Deny => {
+                diagnostic.severity = Severity::Error;
+                diagnostic.notes.push(msg!("note.lint-denied", lint.name()));
+            }
+            LintLevel::Warn if self.warnings_as_errors => {
+                diagnostic.severity = Severity::Error;
+                diagnostic.notes.push(msg!("note.warnings-as-errors", lint.name()));
+            }
+            LintLevel::Warn => {}
+        }
+        Some(diagnostic)
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(|d| d.severity == Severity::Error)
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.diagnostics.iter().filter(|d| d.severity == Severity::Error).count()
+    }
+
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    // Toplanan tanılamaları devreder (derleme sonucuna aktarmak için)
+    pub fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.diagnostics)
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics.iter().filter(|d| d.severity == Severity::Warning)
+    }
+
+    pub fn print_errors(&self) {
+        for diagnostic in self.diagnostics.iter().filter(|d| d.severity == Severity::Error) {
+            eprintln!("{}", diagnostic);
+        }
+    }
+
+    pub fn print_warnings(&self) {
+        for diagnostic in self.warnings() {
+            eprintln!("{}", diagnostic);
+        }
+    }
+
+    // Sahne64 API'sine özgü hata mesajları için yardımcı fonksiyonlar eklenebilir
+    pub fn report_sahne64_api_error(&mut self, api_call: &str, message: String, token: Option<&Token>) {
+        self.report_error(DiagnosticCode::ApiCall, msg!("B0307", api_call, message), token);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_with_snippet_label_and_help() {
+        let at = |line, column, length| SourceLocation { line, column, length };
+        let mut diagnostic = Diagnostic::error(DiagnosticCode::Redefinition, msg!("B0204", "START"), Some(at(3, 2, 6)))
+            .with_label(at(1, 1, 6), msg!("label.first-definition"))
+            .with_note(msg!("note.lint-denied", "unused-label"))
+            .with_help(msg!("help.rename-redefinition"));
+        diagnostic.file = Some("main.b64".to_string());
+        let source = "START:\nJUMP START\n\tSTART:\n";
+
+        assert_eq!(
+            diagnostic.render(Some(source), false, Language::Turkish),
+            concat!(
+                "hata[B0204]: Sembol 'START' zaten bu kapsamda tanımlı\n",
+                " --> main.b64:3:2\n",
+                "  |\n",
+                "1 | START:\n",
+                "  | ------ ilk tanım burada\n",
+                "...\n",
+                "3 |     START:\n",
+                "  |     ^^^^^^\n",
+                "  |\n",
+                "  = not: 'unused-label' kategorisi hata olarak işaretlendi (-D unused-label)\n",
+                "  = yardım: tanımlardan birini yeniden adlandırın\n",
+            )
+        );
+        // Aynı tanılama İngilizce de gösterilebilir; metin yalnızca gösterilirken oluşur
+        let english = diagnostic.render(Some(source), false, Language::English);
+        assert!(english.starts_with("error[B0204]: symbol 'START' is already defined in this scope\n"));
+        assert!(english.contains("  | ------ first defined here\n"));
+        assert!(english.ends_with("  = help: rename one of the definitions\n"));
+        assert_eq!(diagnostic.to_string(), "main.b64: Hata (Satır: 3, Sütun: 2): Sembol 'START' zaten bu kapsamda tanımlı");
+        // Kaynak yoksa yalnızca başlık ve konum yazılır; renkli çıktı ANSI kodları içerir
+        assert_eq!(diagnostic.render(None, false, Language::Turkish).lines().count(), 4);
+        assert!(diagnostic.render(None, true, Language::Turkish).starts_with("\x1b[1;31mhata[B0204]\x1b[0m"));
+    }
+
+    #[test]
+    fn test_json_format_has_one_object_per_line() {
+        let at = |line, column, length| SourceLocation { line, column, length };
+        let mut reporter = ErrorReporter::new();
+        reporter.set_file("main.b64");
+        reporter.report(Diagnostic::error(DiagnosticCode::Redefinition, msg!("B0204", "START"), Some(at(3, 1, 6))).with_label(at(1, 1, 6), msg!("label.first-definition")));
+        reporter.report_error_at(DiagnosticCode::UnresolvedExtern, msg!("B0503", "WORKER"), None);
+
+        let lines: Vec<String> = reporter.diagnostics().iter().map(|d| d.format(MessageFormat::Json, None, false, Language::Turkish)).collect();
+        assert_eq!(lines, vec![
+            concat!(
+                r#"{"severity":"error","code":"B0204","lint":null,"message":"Sembol 'START' zaten bu kapsamda tanımlı","file":"main.b64","#,
+                r#""line_start":3,"column_start":1,"line_end":3,"column_end":7,"#,
+                r#""related":[{"file":"main.b64","line_start":1,"column_start":1,"line_end":1,"column_end":7,"message":"ilk tanım burada"}],"#,
+                r#""notes":[],"help":null}"#,
+                "\n"
+            ),
+            concat!(
+                r#"{"severity":"error","code":"B0503","lint":null,"message":"Çözümlenemeyen harici sembol 'WORKER'","file":"main.b64","#,
+                r#""line_start":null,"column_start":null,"line_end":null,"column_end":null,"related":[],"notes":[],"help":null}"#,
+                "\n"
+            ),
+        ]);
+    }
+}
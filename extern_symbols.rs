@@ -1,118 +1,172 @@
-use std::collections::HashMap;
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum SymbolBinding {
-    Global,
-    External,
-    Sahne64Api, // Sahne64 API fonksiyonları için özel bir bağlama türü
-}
-
-#[derive(Debug, Clone)]
-pub struct ExternSymbol {
-    pub name: String,
-    pub binding: SymbolBinding,
-    pub address: Option<usize>, // Bağlama aşamasında çözümlenecek adres (API için sistem çağrı numarası olabilir)
-}
-
-pub struct ExternSymbolTable {
-    symbols: HashMap<String, ExternSymbol>,
-}
-
-impl ExternSymbolTable {
-    pub fn new() -> Self {
-        ExternSymbolTable {
-            symbols: HashMap::new(),
-        }
-    }
-
-    pub fn declare(&mut self, name: &str, binding: SymbolBinding) {
-        if self.symbols.contains_key(name) {
-            eprintln!("Uyarı: Sembol '{}' zaten tanımlı", name);
-        }
-        self.symbols.insert(
-            name.to_string(),
-            ExternSymbol {
-                name: name.to_string(),
-                binding,
-                address: None,
-            },
-        );
-    }
-
-    pub fn resolve(&mut self, name: &str, address: usize) {
-        if let Some(symbol) = self.symbols.get_mut(name) {
-            symbol.address = Some(address);
-        } else {
-            eprintln!("Uyarı: Çözümlenmeye çalışılan tanımsız sembol '{}'", name);
-        }
-    }
-
-    pub fn lookup(&self, name: &str) -> Option<&ExternSymbol> {
-        self.symbols.get(name)
-    }
-}
-
-// Semantik analiz aşamasında harici sembollerin ve Sahne64 API fonksiyonlarının toplanması için
-use crate::parser::AstNode;
-use crate::semantic_analyzer::SemanticAnalyzer;
-
-impl SemanticAnalyzer {
-    pub fn collect_extern_symbols(&mut self, ast: &AstNode) {
-        match ast {
-            AstNode::Program(statements) => {
-                for statement in statements {
-                    self.collect_extern_symbol_statement(statement);
-                }
-            }
-            _ => {}
-        }
-    }
-
-    fn collect_extern_symbol_statement(&mut self, node: &AstNode) {
-        match node {
-            AstNode::Instruction { opcode, operands } => {
-                if opcode == "GLOBAL" && operands.len() == 1 {
-                    if let crate::parser::Expression::Identifier(name) = &operands[0] {
-                        self.extern_symbol_table.declare(name, SymbolBinding::Global);
-                    } else {
-                        eprintln!("Uyarı: GLOBAL direktifi bir tanımlayıcı almalı");
-                    }
-                } else if opcode == "EXTERN" && operands.len() == 1 {
-                    if let crate::parser::Expression::Identifier(name) = &operands[0] {
-                        self.extern_symbol_table.declare(name, SymbolBinding::External);
-                    } else {
-                        eprintln!("Uyarı: EXTERN direktifi bir tanımlayıcı almalı");
-                    }
-                } else if opcode == "SAHNE64_API" && operands.len() == 2 {
-                    if let (crate::parser::Expression::Identifier(api_name), crate::parser::Expression::Number(api_id)) = (&operands[0], &operands[1]) {
-                        self.extern_symbol_table.declare(api_name, SymbolBinding::Sahne64Api);
-                        self.extern_symbol_table.resolve(api_name, *api_id as usize); // API ID'sini adres olarak kaydet
-                    } else {
-                        eprintln!("Uyarı: SAHNE64_API direktifi API adı ve ID almalı");
-                    }
-                }
-            }
-            _ => {}
-        }
-    }
-}
- use crate::linker::Linker;
-//
- impl Linker {
-     pub fn resolve_extern_symbols(&mut self, object_code: &Vec<String>, symbol_table: &mut ExternSymbolTable) -> Vec<String> {
-         let mut resolved_code = object_code.clone();
-         for (i, line) in object_code.iter().enumerate() {
-             for (symbol_name, symbol) in &symbol_table.symbols {
-                 if let Some(address) = symbol.address {
-                     // SAHNE64_API sembollerini sistem çağrı numaralarıyla değiştir
-                     if symbol.binding == SymbolBinding::Sahne64Api || symbol.binding == SymbolBinding::External {
-                         resolved_code[i] = resolved_code[i].replace(symbol_name, &format!("{}", address));
-                     }
-                 } else if symbol.binding == SymbolBinding::External {
-                     eprintln!("Uyarı: Çözümlenemeyen harici sembol '{}'", symbol_name);
-                 }
-             }
-         }
-         resolved_code
-     }
+use crate::messages::{msg, Message};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SymbolBinding {
+    Global,
+    External,
+    Sahne64Api, // Sahne64 API fonksiyonları için özel bir bağlama türü
+}
+
+#[derive(Debug, Clone)]
+pub struct ExternSymbol {
+    pub name: String,
+    pub binding: SymbolBinding,
+    pub address: Option<usize>, // Bağlama aşamasında çözümlenecek adres (API için sistem çağrı numarası olabilir)
+}
+
+pub struct ExternSymbolTable {
+    symbols: HashMap<String, ExternSymbol>,
+}
+
+impl Default for ExternSymbolTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExternSymbolTable {
+    pub fn new() -> Self {
+        ExternSymbolTable {
+            symbols: HashMap::new(),
+        }
+    }
+
+    // Sembolü kaydeder; daha önce bildirilmişse önceki bağlama türünü döndürür
+    pub fn declare(&mut self, name: &str, binding: SymbolBinding) -> Option<SymbolBinding> {
+        self.symbols
+            .insert(
+                name.to_string(),
+                ExternSymbol {
+                    name: name.to_string(),
+                    binding,
+                    address: None,
+                },
+            )
+            .map(|previous| previous.binding)
+    }
+
+    // Bildirilmiş sembolün adresini kaydeder; bildirilmemiş sembol bir iç hatadır
+    pub fn resolve(&mut self, name: &str, address: usize) -> Result<(), Message> {
+        match self.symbols.get_mut(name) {
+            Some(symbol) => {
+                symbol.address = Some(address);
+                Ok(())
+            }
+            None => Err(msg!("B0901.extern-resolve", name)),
+        }
+    }
+
+    pub fn lookup(&self, name: &str) -> Option<&ExternSymbol> {
+        self.symbols.get(name)
+    }
+
+    // Verilen bağlama türündeki sembol adlarını sıralı döndürür
+    pub fn names_with_binding(&self, binding: SymbolBinding) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .symbols
+            .values()
+            .filter(|symbol| symbol.binding == binding)
+            .map(|symbol| symbol.name.clone())
+            .collect();
+        names.sort();
+        names
+    }
+}
+
+// Semantik analiz aşamasında harici sembollerin ve Sahne64 API fonksiyonlarının toplanması için
+use crate::diagnostic_code::DiagnosticCode;
+use crate::error_reporter::Lint;
+use crate::parser::AstNode;
+use crate::semantic_analyzer::SemanticAnalyzer;
+
+impl SemanticAnalyzer {
+    pub fn collect_extern_symbols(&mut self, ast: &AstNode) {
+        if let AstNode::Program(statements) = ast {
+            for statement in statements {
+                self.current_location = Some(statement.location);
+                self.collect_extern_symbol_statement(&statement.node);
+            }
+        }
+    }
+
+    pub(crate) fn is_external(&self, name: &str) -> bool {
+        self.extern_symbol_table
+            .lookup(name)
+            .is_some_and(|symbol| symbol.binding == SymbolBinding::External)
+    }
+
+    fn collect_extern_symbol_statement(&mut self, node: &AstNode) {
+        if let AstNode::Instruction { opcode, operands } = node {
+            if opcode == "GLOBAL" && operands.len() == 1 {
+                if let crate::parser::Expression::Identifier(name) = &operands[0] {
+                    self.declare_linkage(opcode, name, SymbolBinding::Global);
+                } else {
+                    self.report_error(DiagnosticCode::InvalidOperand, msg!("B0306.directive", opcode));
+                }
+            } else if opcode == "EXTERN" && operands.len() == 1 {
+                if let crate::parser::Expression::Identifier(name) = &operands[0] {
+                    self.declare_linkage(opcode, name, SymbolBinding::External);
+                } else {
+                    self.report_error(DiagnosticCode::InvalidOperand, msg!("B0306.directive", opcode));
+                }
+            } else if opcode == "SAHNE64_API" && operands.len() == 2 {
+                if let (crate::parser::Expression::Identifier(api_name), crate::parser::Expression::Number(api_id)) = (&operands[0], &operands[1]) {
+                    self.extern_symbol_table.declare(api_name, SymbolBinding::Sahne64Api);
+                    // API ID'si adres olarak kaydedilir
+                    if let Err(message) = self.extern_symbol_table.resolve(api_name, *api_id as usize) {
+                        self.report_error(DiagnosticCode::Internal, message);
+                    }
+                } else {
+                    self.report_error(DiagnosticCode::InvalidOperand, msg!("B0306.api"));
+                }
+            }
+        }
+    }
+
+    // GLOBAL/EXTERN bildirimi; aynı bildirimin tekrarı uyarı, çelişen bildirim hatadır
+    fn declare_linkage(&mut self, directive: &str, name: &str, binding: SymbolBinding) {
+        match self.extern_symbol_table.declare(name, binding.clone()) {
+            Some(previous) if previous == binding => {
+                self.report_warning(Lint::RedundantDeclaration, msg!("B0605", name, directive));
+            }
+            Some(SymbolBinding::Global) | Some(SymbolBinding::External) => {
+                self.report_error(DiagnosticCode::ConflictingLinkage, msg!("B0205", name));
+            }
+            _ => {}
+        }
+    }
+}
+ use crate::linker::{LinkError, Linker};
+//
+ impl Linker {
+     // Adresi bilinen API ve harici sembolleri adresleriyle değiştirir; adresi
+     // olmayan harici semboller nesne dosyası adıyla birlikte hata olarak döner
+     pub fn resolve_extern_symbols(&mut self, object: &str, object_code: &[String], symbol_table: &ExternSymbolTable) -> Result<Vec<String>, Vec<LinkError>> {
+         let mut unresolved: Vec<&String> = symbol_table
+             .symbols
+             .iter()
+             .filter(|(_, symbol)| symbol.binding == SymbolBinding::External && symbol.address.is_none())
+             .map(|(name, _)| name)
+             .collect();
+         if !unresolved.is_empty() {
+             unresolved.sort();
+             return Err(unresolved
+                 .into_iter()
+                 .map(|name| LinkError { object: object.to_string(), code: DiagnosticCode::UnresolvedExtern, message: msg!("B0503", name) })
+                 .collect());
+         }
+         let mut resolved_code = object_code.to_vec();
+         for line in resolved_code.iter_mut() {
+             for (symbol_name, symbol) in &symbol_table.symbols {
+                 if let Some(address) = symbol.address {
+                     // SAHNE64_API sembollerini sistem çağrı numaralarıyla değiştir
+                     if symbol.binding == SymbolBinding::Sahne64Api || symbol.binding == SymbolBinding::External {
+                         *line = line.replace(symbol_name, &format!("{}", address));
+                     }
+                 }
+             }
+         }
+         Ok(resolved_code)
+     }
  }
\ No newline at end of file
@@ -0,0 +1,61 @@
+use crate::messages::msg;
+use std::collections::HashMap;
+use std::io;
+
+// Derleyiciye verilen adlandırılmış kaynak metni
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceFile {
+    pub name: String,
+    pub text: String,
+}
+
+impl SourceFile {
+    pub fn new(name: &str, text: &str) -> Self {
+        SourceFile {
+            name: name.to_string(),
+            text: text.to_string(),
+        }
+    }
+}
+
+// Derleyicinin dosya sistemine erişimini soyutlar. Komut satırı aracı gerçek
+// dosyalar üzerinde, testler ve editör eklentileri bellekte uygular.
+pub trait FileProvider {
+    fn read_source(&self, name: &str) -> io::Result<SourceFile>;
+    fn write_output(&mut self, name: &str, lines: &[String]) -> io::Result<()>;
+}
+
+// Tamamen bellekte çalışan dosya sağlayıcı
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFileProvider {
+    files: HashMap<String, String>,
+}
+
+impl MemoryFileProvider {
+    pub fn new() -> Self {
+        MemoryFileProvider { files: HashMap::new() }
+    }
+
+    pub fn insert(&mut self, name: &str, text: &str) {
+        self.files.insert(name.to_string(), text.to_string());
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.files.get(name).map(|text| text.as_str())
+    }
+}
+
+impl FileProvider for MemoryFileProvider {
+    fn read_source(&self, name: &str) -> io::Result<SourceFile> {
+        match self.files.get(name) {
+            Some(text) => Ok(SourceFile::new(name, text)),
+            None => Err(io::Error::new(io::ErrorKind::NotFound, msg!("driver.file-not-found", name))),
+        }
+    }
+
+    fn write_output(&mut self, name: &str, lines: &[String]) -> io::Result<()> {
+        let text: String = lines.iter().map(|line| format!("{}\n", line)).collect();
+        self.files.insert(name.to_string(), text);
+        Ok(())
+    }
+}
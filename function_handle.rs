@@ -1,75 +1,76 @@
-use crate::parser::{AstNode, Expression};
-use crate::semantic_analyzer::SemanticAnalyzer;
-use crate::symbol_table::{Symbol, SymbolType, Scope};
-use crate::types::Type; // Eğer fonksiyon tiplerini tutacaksanız
-
-impl SemanticAnalyzer {
-    pub fn analyze_functions(&mut self, ast: &AstNode) {
-        match ast {
-            AstNode::Program(statements) => {
-                for statement in statements {
-                    self.analyze_function_statement(statement);
-                }
-            }
-            _ => {}
-        }
-    }
-
-    fn analyze_function_statement(&mut self, node: &AstNode) {
-        match node {
-            AstNode::Instruction { opcode, operands } => {
-                if opcode == "CALL" {
-                    self.resolve_procedure_call(operands);
-                }
-            }
-            // Prosedür tanımlama
-            AstNode::Label { name } if name.starts_with("PROCEDURE_") => {
-                let proc_name = name.split_at("PROCEDURE_".len()).1;
-                self.declare_procedure(proc_name);
-                self.enter_scope(Scope::Local(proc_name.to_string()));
-                // Prosedür içindeki parametreleri ve yerel değişkenleri analiz et
-                // ...
-            }
-            // Prosedür sonu - AST'ye eklenmesi gerekebilir
-            // ...
-            AstNode::SpawnTask { function, .. } => {
-                // SPAWN komutundaki 'function' bir prosedür olmalı
-                self.resolve_procedure(function);
-                // Öncelik argümanı zaten semantik analizde kontrol ediliyor
-            }
-            _ => {}
-        }
-    }
-
-    fn declare_procedure(&mut self, name: &str) {
-        let symbol = Symbol {
-            name: name.to_string(),
-            symbol_type: SymbolType::Procedure,
-            scope: Scope::Global, // Prosedürler genellikle global kapsamdadır
-        };
-        if self.symbol_table.lookup(name).is_some() {
-            panic!("Prosedür '{}' zaten tanımlı", name);
-        }
-        self.symbol_table.insert(symbol);
-    }
-
-    fn resolve_procedure_call(&self, operands: &Vec<Expression>) {
-        if let Some(Expression::Identifier(proc_name)) = operands.first() {
-            if self.symbol_table.lookup(proc_name).filter(|s| s.symbol_type == SymbolType::Procedure).is_none() {
-                panic!("Tanımsız prosedür '{}'", proc_name);
-            }
-            // Argüman sayısını ve tiplerini kontrol edebilirsiniz (ileride eklenecek)
-        } else {
-            panic!("CALL komutu geçerli bir prosedür adı almalı");
-        }
-    }
-
-    fn enter_scope(&mut self, scope: Scope) {
-        self.current_scope = scope;
-    }
-
-    fn exit_scope(&mut self) {
-        self.current_scope = Scope::Global; // Basit kapsam çıkışı
-        // Yerel kapsamdaki sembolleri temizleme mantığı eklenebilir
-    }
+use crate::diagnostic_code::DiagnosticCode;
+use crate::messages::msg;
+use crate::parser::{AstNode, Expression};
+use crate::semantic_analyzer::SemanticAnalyzer;
+use crate::symbol_table::{Symbol, SymbolType, Scope};
+
+impl SemanticAnalyzer {
+    pub fn analyze_functions(&mut self, ast: &AstNode) {
+        if let AstNode::Program(statements) = ast {
+            for statement in statements {
+                self.enter_statement(statement);
+                self.analyze_function_statement(&statement.node);
+            }
+        }
+    }
+
+    fn analyze_function_statement(&mut self, node: &AstNode) {
+        match node {
+            AstNode::Instruction { opcode, operands } if opcode == "CALL" => {
+                self.in_operand(0, |this| this.resolve_procedure_call(operands));
+            }
+            // Prosedür tanımlama; sembol analyze'ın ön geçişinde tanımlandı
+            AstNode::Label { name } if name.starts_with("PROCEDURE_") => {
+                let proc_name = name.split_at("PROCEDURE_".len()).1;
+                self.enter_scope(Scope::Local(proc_name.to_string()));
+                // Prosedür içindeki parametreleri ve yerel değişkenleri analiz et
+                // ...
+            }
+            // Prosedür sonu - AST'ye eklenmesi gerekebilir
+            // ...
+            AstNode::SpawnTask { function, .. } => {
+                // SPAWN komutundaki 'function' bir prosedür olmalı
+                self.resolve_procedure(function);
+                // Öncelik argümanı zaten semantik analizde kontrol ediliyor
+            }
+            _ => {}
+        }
+    }
+
+    pub(crate) fn declare_procedure(&mut self, name: &str) {
+        let symbol = Symbol {
+            name: name.to_string(),
+            symbol_type: SymbolType::Procedure,
+            scope: Scope::Global, // Prosedürler genellikle global kapsamdadır
+            location: self.current_location,
+        };
+        if let Some(previous) = self.symbol_table.lookup(name) {
+            let previous = previous.location;
+            self.report_redefinition(msg!("B0204.procedure", name), previous);
+            return;
+        }
+        self.warn_if_confusable(name);
+        self.symbol_table.insert(symbol);
+    }
+
+    fn resolve_procedure_call(&mut self, operands: &[Expression]) {
+        if let Some(Expression::Identifier(proc_name)) = operands.first() {
+            if self.symbol_table.lookup(proc_name).filter(|s| s.symbol_type == SymbolType::Procedure).is_none() {
+                self.report_error(DiagnosticCode::UndefinedProcedure, msg!("B0203", proc_name));
+            }
+            // Argüman sayısını ve tiplerini kontrol edebilirsiniz (ileride eklenecek)
+        } else {
+            self.report_error(DiagnosticCode::InvalidOperand, msg!("B0306.call"));
+        }
+    }
+
+    fn enter_scope(&mut self, scope: Scope) {
+        self.current_scope = scope;
+    }
+
+    #[allow(dead_code)] // Prosedür sonu AST'ye eklendiğinde kullanılacak
+    fn exit_scope(&mut self) {
+        self.current_scope = Scope::Global; // Basit kapsam çıkışı
+        // Yerel kapsamdaki sembolleri temizleme mantığı eklenebilir
+    }
 }
\ No newline at end of file
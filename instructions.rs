@@ -0,0 +1,151 @@
+// Genel komutların (mnemonic) tablosu ve işlenen imzaları. Parser her komutun
+// işlenen sayısını ve her işlenenin biçimini bu tabloya göre denetler; lexer da
+// komut adlarını buradan tanır. Yeni bir komut eklemek için tabloya bir satır
+// eklemek yeterlidir.
+
+use crate::keywords::CaseSensitivity;
+use crate::parser::Expression;
+
+// Ayrıştırılmış bir işlenenin biçimi
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperandKind {
+    Register,  // R3
+    Immediate, // 42, 'A', ZF, . ve sabit ifadeler (hello_len + 1)
+    String,    // "metin"
+    Address,   // $ad
+    Memory,    // [R1 + 4], [sayac]
+    Name,      // Değişken, sabit, etiket veya prosedür adı
+}
+
+impl OperandKind {
+    pub fn of(expression: &Expression) -> OperandKind {
+        match expression {
+            Expression::Register(_) => OperandKind::Register,
+            Expression::StringLiteral(_) => OperandKind::String,
+            Expression::AddressOf(_) => OperandKind::Address,
+            Expression::Memory(_) => OperandKind::Memory,
+            Expression::Identifier(_) => OperandKind::Name,
+            Expression::Number(_)
+            | Expression::Flag(_)
+            | Expression::Handle(_)
+            | Expression::TaskId(_)
+            | Expression::LocationCounter
+            | Expression::Binary { .. } => OperandKind::Immediate,
+        }
+    }
+
+    // Tanılamalarda kullanılan, mesaj kataloğundaki adı
+    pub fn key(&self) -> &'static str {
+        match self {
+            OperandKind::Register => "operand-kind.register",
+            OperandKind::Immediate => "operand-kind.immediate",
+            OperandKind::String => "operand-kind.string",
+            OperandKind::Address => "operand-kind.address",
+            OperandKind::Memory => "operand-kind.memory",
+            OperandKind::Name => "operand-kind.name",
+        }
+    }
+}
+
+// Bir komutun bir işlenen yerinde kabul ettiği biçimler
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperandSlot {
+    Register,    // Yalnızca register
+    Destination, // Yazılabilen yer: register, değişken veya bellek
+    Source,      // Okunabilen değer: register, sabit, değişken, adres veya bellek
+    Output,      // Source veya string sabiti (WRITE)
+    Label,       // Atlanacak etiket
+    Procedure,   // Çağrılacak prosedür
+}
+
+impl OperandSlot {
+    pub fn accepts(&self, kind: OperandKind) -> bool {
+        match self {
+            OperandSlot::Register => kind == OperandKind::Register,
+            OperandSlot::Destination => matches!(kind, OperandKind::Register | OperandKind::Memory | OperandKind::Name),
+            OperandSlot::Source => kind != OperandKind::String,
+            OperandSlot::Output => true,
+            OperandSlot::Label | OperandSlot::Procedure => kind == OperandKind::Name,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            OperandSlot::Register => "operand.register",
+            OperandSlot::Destination => "operand.destination",
+            OperandSlot::Source => "operand.source",
+            OperandSlot::Output => "operand.output",
+            OperandSlot::Label => "operand.label",
+            OperandSlot::Procedure => "operand.procedure",
+        }
+    }
+}
+
+use OperandSlot::*;
+
+pub const INSTRUCTIONS: &[(&str, &[OperandSlot])] = &[
+    // Veri taşıma
+    ("LOAD", &[Register, Source]),
+    ("MOV", &[Destination, Source]),
+    // Aritmetik ve mantık; sonuç ilk işlenene yazılır
+    ("ADD", &[Destination, Source]),
+    ("SUB", &[Destination, Source]),
+    ("MUL", &[Destination, Source]),
+    ("DIV", &[Destination, Source]),
+    ("AND", &[Destination, Source]),
+    ("OR", &[Destination, Source]),
+    ("XOR", &[Destination, Source]),
+    ("NOT", &[Destination]),
+    ("CMP", &[Source, Source]),
+    // Koşulsuz atlama; JUMP deyiminin komut tablosundaki eş adı
+    ("JMP", &[Label]),
+    // Son CMP'nin (veya SYSCALL'ın R0'daki dönüş değerinin) sonucuna göre atlamalar
+    ("JEQ", &[Label]),
+    ("JNE", &[Label]),
+    ("JLT", &[Label]),
+    ("JLE", &[Label]),
+    ("JGT", &[Label]),
+    ("JGE", &[Label]),
+    // Prosedürler ve sistem çağrıları
+    ("CALL", &[Procedure]),
+    ("RET", &[]),
+    ("SYSCALL", &[]),
+    // Giriş/çıkış
+    ("READ", &[Destination]),
+    ("WRITE", &[Output]),
+];
+
+// Komutun tablodaki yazımı ve işlenen imzası
+pub fn lookup(name: &str, case: CaseSensitivity) -> Option<(&'static str, &'static [OperandSlot])> {
+    INSTRUCTIONS.iter().find(|(opcode, _)| case.matches(opcode, name)).copied()
+}
+
+// Tablodaki yazımıyla verilen komutun işlenen imzası
+pub fn signature(opcode: &str) -> Option<&'static [OperandSlot]> {
+    INSTRUCTIONS.iter().find(|(name, _)| *name == opcode).map(|(_, slots)| *slots)
+}
+
+// Komutun index'inci işlenen yeri; semantik analiz adları buna göre çözümler
+pub fn slot(opcode: &str, index: usize) -> Option<OperandSlot> {
+    signature(opcode).and_then(|slots| slots.get(index).copied())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instruction_table_and_operand_slots() {
+        let names: std::collections::HashSet<&str> = INSTRUCTIONS.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names.len(), INSTRUCTIONS.len());
+        assert_eq!(lookup("load", CaseSensitivity::Insensitive), Some(("LOAD", &[Register, Source][..])));
+        assert_eq!(lookup("load", CaseSensitivity::Sensitive), None);
+
+        assert!(Source.accepts(OperandKind::of(&Expression::AddressOf("MSG".to_string()))));
+        assert!(!Destination.accepts(OperandKind::Immediate));
+        assert!(!Source.accepts(OperandKind::String) && Output.accepts(OperandKind::String));
+        assert!(Label.accepts(OperandKind::Name) && !Label.accepts(OperandKind::Register));
+        assert_eq!(slot("JLT", 0), Some(Label));
+        assert_eq!(slot("RET", 0), None);
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt;
+
+// Makine tarafından okunacak çıktılar (--emit, tanılamalar) için küçük bir JSON değeri.
+// Nesne alanları eklenme sırasını korur, böylece çıktı kararlıdır.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(i64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    pub fn object(fields: Vec<(&str, JsonValue)>) -> Self {
+        JsonValue::Object(fields.into_iter().map(|(key, value)| (key.to_string(), value)).collect())
+    }
+
+    pub fn string(value: &str) -> Self {
+        JsonValue::String(value.to_string())
+    }
+
+    pub fn strings(values: &[String]) -> Self {
+        JsonValue::Array(values.iter().map(|value| JsonValue::string(value)).collect())
+    }
+}
+
+impl From<Option<JsonValue>> for JsonValue {
+    fn from(value: Option<JsonValue>) -> Self {
+        value.unwrap_or(JsonValue::Null)
+    }
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonValue::Null => write!(f, "null"),
+            JsonValue::Bool(value) => write!(f, "{}", value),
+            JsonValue::Number(value) => write!(f, "{}", value),
+            JsonValue::String(value) => write_escaped(f, value),
+            JsonValue::Array(items) => {
+                write!(f, "[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+            JsonValue::Object(fields) => {
+                write!(f, "{{")?;
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_escaped(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_escaped(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for ch in value.chars() {
+        match ch {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_serialization_escapes_strings() {
+        let value = JsonValue::object(vec![
+            ("ad", JsonValue::string("Merhaba \"Dünya\"\n")),
+            ("değerler", JsonValue::Array(vec![JsonValue::Number(-1), JsonValue::Bool(true), JsonValue::Null])),
+        ]);
+        assert_eq!(value.to_string(), r#"{"ad":"Merhaba \"Dünya\"\n","değerler":[-1,true,null]}"#);
+    }
+}
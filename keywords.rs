@@ -0,0 +1,98 @@
+// Lexer'ın tanıdığı ayrılmış sözcükler: parser'ın özel olarak ayrıştırdığı
+// anahtar kelimeler ve flag adları bu tabloda, genel komutların (mnemonic) adları
+// işlenen imzalarıyla birlikte instructions.rs'deki tabloda tutulur.
+
+use crate::instructions;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordKind {
+    Keyword,  // JUMP, ALLOCATE ... AS gibi kendine özgü sözdizimi olan deyimler ve yardımcı sözcükler
+    Mnemonic, // Operandları virgülle ayrılmış genel komutlar (ADD, CALL, RET ...)
+    Flag,     // İşlemci durum bayrakları
+}
+
+pub const RESERVED_WORDS: &[(&str, WordKind)] = &[
+    // Deyimler
+    ("JUMP", WordKind::Keyword),
+    ("FLAG", WordKind::Keyword),
+    ("ALLOCATE", WordKind::Keyword),
+    ("RELEASE", WordKind::Keyword),
+    ("SPAWN", WordKind::Keyword),
+    ("EXIT", WordKind::Keyword),
+    ("SLEEP", WordKind::Keyword),
+    ("YIELD", WordKind::Keyword),
+    ("ACQUIRE", WordKind::Keyword),
+    ("CTRL", WordKind::Keyword),
+    ("SEND", WordKind::Keyword),
+    ("RECV", WordKind::Keyword),
+    ("GET_TASK_ID", WordKind::Keyword),
+    ("GET_CORE_ID", WordKind::Keyword),
+    ("GET_TOTAL_CORES", WordKind::Keyword),
+    ("GLOBAL", WordKind::Keyword),
+    ("EXTERN", WordKind::Keyword),
+    // Yardımcı sözcükler ve makrolar
+    ("AS", WordKind::Keyword),
+    ("WITH", WordKind::Keyword),
+    ("MACRO", WordKind::Keyword),
+    ("ENDMACRO", WordKind::Keyword),
+    // Bildirimler
+    ("VAR", WordKind::Keyword),
+    // Flag'ler: sıfır, elde, işaret ve taşma (kısa ve uzun adlarıyla)
+    ("Z", WordKind::Flag),
+    ("ZF", WordKind::Flag),
+    ("C", WordKind::Flag),
+    ("CF", WordKind::Flag),
+    ("S", WordKind::Flag),
+    ("SF", WordKind::Flag),
+    ("O", WordKind::Flag),
+    ("OF", WordKind::Flag),
+];
+
+// Ayrılmış sözcüklerin (ve register adlarının) hangi yazımlarının tanınacağı.
+// Etiket, değişken ve prosedür adları her iki kuralda da büyük/küçük harfe duyarlıdır.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CaseSensitivity {
+    #[default]
+    Insensitive, // "jump", "Jump" ve "JUMP" aynı anahtar kelimedir; token tablodaki yazımı taşır
+    Sensitive,   // Yalnızca tablodaki büyük harfli yazım tanınır; "jump" bir addır
+}
+
+impl CaseSensitivity {
+    pub fn matches(&self, reserved: &str, word: &str) -> bool {
+        match self {
+            CaseSensitivity::Insensitive => reserved.eq_ignore_ascii_case(word),
+            CaseSensitivity::Sensitive => reserved == word,
+        }
+    }
+}
+
+// Sözcük ayrılmışsa tablodaki yazımı ve türü
+pub fn classify(word: &str, case: CaseSensitivity) -> Option<(&'static str, WordKind)> {
+    RESERVED_WORDS
+        .iter()
+        .find(|(reserved, _)| case.matches(reserved, word))
+        .copied()
+        .or_else(|| instructions::lookup(word, case).map(|(opcode, _)| (opcode, WordKind::Mnemonic)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_reserved_words_are_unique_and_classified_by_policy() {
+        let names: HashSet<&str> = RESERVED_WORDS.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names.len(), RESERVED_WORDS.len());
+        assert!(RESERVED_WORDS.iter().all(|(name, _)| *name == name.to_ascii_uppercase()));
+        assert!(instructions::INSTRUCTIONS.iter().all(|(opcode, _)| !names.contains(opcode) && *opcode == opcode.to_ascii_uppercase()));
+
+        assert_eq!(classify("spawn", CaseSensitivity::Insensitive), Some(("SPAWN", WordKind::Keyword)));
+        assert_eq!(classify("Ret", CaseSensitivity::Insensitive), Some(("RET", WordKind::Mnemonic)));
+        assert_eq!(classify("zf", CaseSensitivity::Insensitive), Some(("ZF", WordKind::Flag)));
+        assert_eq!(classify("spawn", CaseSensitivity::Sensitive), None);
+        assert_eq!(classify("OR", CaseSensitivity::Sensitive), Some(("OR", WordKind::Mnemonic)));
+        assert_eq!(classify("var", CaseSensitivity::Insensitive), Some(("VAR", WordKind::Keyword)));
+        assert_eq!(classify("target", CaseSensitivity::Insensitive), None);
+    }
+}
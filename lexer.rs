@@ -1,216 +1,849 @@
-#[derive(Debug, PartialEq, Clone)]
-pub enum TokenType {
-    Keyword(String),     // JUMP, FLAG, ALLOCATE vb.
-    Identifier(String),  // Değişken, etiket, prosedür adı
-    Number(i64),
-    Flag(String),        // ZF, CF vb.
-    Handle(usize),       // Sahne64 Handle (sayısal olarak temsil edilebilir)
-    TaskId(usize),       // Sahne64 Task ID (sayısal olarak temsil edilebilir)
-    ResourceId(String),  // Kaynak adı (string)
-    Colon,               // :
-    Comma,               // ,
-    OpenParen,           // (
-    CloseParen,          // )
-    Equals,              // =
-    StringLiteral(String), // Örneğin kaynak adları için
-    EndOfFile,
-    Unknown(char),
-}
-
-#[derive(Debug, Clone)]
-pub struct Token {
-    pub token_type: TokenType,
-    pub line: usize,
-    pub column: usize,
-}
-
-pub struct Lexer {
-    input: String,
-    position: usize,
-    line: usize,
-    column: usize,
-}
-
-impl Lexer {
-    pub fn new(input: String) -> Self {
-        Lexer {
-            input,
-            position: 0,
-            line: 1,
-            column: 1,
-        }
-    }
-
-    fn peek(&self) -> Option<char> {
-        self.input.chars().nth(self.position)
-    }
-
-    fn advance(&mut self) -> Option<char> {
-        let current = self.peek();
-        if let Some(c) = current {
-            self.position += 1;
-            self.column += 1;
-            Some(c)
-        } else {
-            None
-        }
-    }
-
-    fn skip_whitespace(&mut self) {
-        while let Some(c) = self.peek() {
-            if c.is_whitespace() {
-                if c == '\n' {
-                    self.line += 1;
-                    self.column = 1;
-                }
-                self.advance();
-            } else {
-                break;
-            }
-        }
-    }
-
-    pub fn next_token(&mut self) -> Token {
-        self.skip_whitespace();
-
-        match self.peek() {
-            Some(c) => {
-                match c {
-                    ':' => self.single_char_token(TokenType::Colon),
-                    ',' => self.single_char_token(TokenType::Comma),
-                    '(' => self.single_char_token(TokenType::OpenParen),
-                    ')' => self.single_char_token(TokenType::CloseParen),
-                    '=' => self.single_char_token(TokenType::Equals),
-                    '"' => self.string_literal(),
-                    'J' | 'U' | 'M' | 'P' | 'F' | 'L' | 'A' | 'G' | // Temel anahtar kelimeler
-                    'A' | 'L' | 'O' | 'C' | 'T' | 'E' |           // ALLOCATE
-                    'R' | 'E' | 'L' | 'S' | 'A' | 'E' |           // RELEASE
-                    'S' | 'P' | 'A' | 'W' | 'N' |                 // SPAWN
-                    'E' | 'X' | 'I' | 'T' |                       // EXIT
-                    'S' | 'L' | 'E' | 'E' | 'P' |                 // SLEEP
-                    'Y' | 'I' | 'E' | 'L' | 'D' |                 // YIELD
-                    'A' | 'C' | 'Q' | 'U' | 'I' | 'R' | 'E' |       // ACQUIRE
-                    'C' | 'T' | 'R' | 'L' |                       // CTRL
-                    'S' | 'E' | 'N' | 'D' |                       // SEND
-                    'R' | 'E' | 'C' | 'V' |                       // RECV
-                    'G' | 'E' | 'T' | '_' => {                    // GET_...
-                        self.identifier_or_keyword()
-                    }
-                    'Z' | 'C' | 'S' | 'O' => self.flag(),
-                    '0'..='9' | '-' => self.number(),
-                    other => {
-                        self.advance();
-                        Token { token_type: TokenType::Unknown(other), line: self.line, column: self.column - 1 }
-                    }
-                }
-            }
-            None => Token { token_type: TokenType::EndOfFile, line: self.line, column: self.column },
-        }
-    }
-
-    fn single_char_token(&mut self, token_type: TokenType) -> Token {
-        let token = Token { token_type, line: self.line, column: self.column };
-        self.advance();
-        token
-    }
-
-    fn identifier_or_keyword(&mut self) -> Token {
-        let mut identifier = String::new();
-        let start_column = self.column;
-        while let Some(ch) = self.peek() {
-            if ch.is_alphanumeric() || ch == '_' {
-                identifier.push(ch);
-                self.advance();
-            } else {
-                break;
-            }
-        }
-        let token_type = match identifier.as_str() {
-            "JUMP" | "FLAG" | "ALLOCATE" | "RELEASE" | "SPAWN" | "EXIT" | "SLEEP" | "YIELD" |
-            "ACQUIRE" | "CTRL" | "SEND" | "RECV" | "GET_TASK_ID" | "GET_CORE_ID" | "GET_TOTAL_CORES" => {
-                TokenType::Keyword(identifier)
-            }
-            _ => TokenType::Identifier(identifier),
-        };
-        Token { token_type, line: self.line, column: start_column }
-    }
-
-    fn flag(&mut self) -> Token {
-        let mut flag = String::new();
-        let start_column = self.column;
-        while let Some(ch) = self.peek() {
-            if ch.is_alphabetic() {
-                flag.push(ch);
-                self.advance();
-            } else {
-                break;
-            }
-        }
-        Token { token_type: TokenType::Flag(flag), line: self.line, column: start_column }
-    }
-
-    fn number(&mut self) -> Token {
-        let mut number = String::new();
-        let start_column = self.column;
-        while let Some(ch) = self.peek() {
-            if ch.is_digit(10) || ch == '-' {
-                number.push(ch);
-                self.advance();
-            } else {
-                break;
-            }
-        }
-        if let Ok(num) = number.parse::<i64>() {
-            // Burada Handle veya TaskId olabilecek sayıları ayırt etmek için bağlama duyarlı analiz gerekebilir.
-            // Şimdilik sadece Number olarak kabul edelim ve Parser'da daha detaylı kontrol edelim.
-            Token { token_type: TokenType::Number(num), line: self.line, column: start_column }
-        } else {
-            Token { token_type: TokenType::Unknown(number.chars().next().unwrap_or('\0')), line: self.line, column: start_column }
-        }
-    }
-
-    fn string_literal(&mut self) -> Token {
-        self.advance(); // Açılış tırnağını atla
-        let mut value = String::new();
-        let start_column = self.column;
-        while let Some(ch) = self.peek() {
-            self.advance();
-            if ch == '"' {
-                return Token { token_type: TokenType::StringLiteral(value), line: self.line, column: start_column };
-            }
-            value.push(ch);
-        }
-        Token { token_type: TokenType::Unknown('"'), line: self.line, column: start_column } // Kapanış tırnağı yok hatası
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_lexer_with_sahne64() {
-        let input = "ALLOCATE 1024 AS handle1\nSPAWN task_func WITH prio=2\nACQUIRE \"my_resource\" AS res1\nSEND handle1, message";
-        let mut lexer = Lexer::new(input.to_string());
-
-        assert_eq!(lexer.next_token().token_type, TokenType::Keyword("ALLOCATE".to_string()));
-        assert_eq!(lexer.next_token().token_type, TokenType::Number(1024));
-        assert_eq!(lexer.next_token().token_type, TokenType::Keyword("AS".to_string())); // "AS" anahtar kelime olarak eklenebilir
-        assert_eq!(lexer.next_token().token_type, TokenType::Identifier("handle1".to_string()));
-        assert_eq!(lexer.next_token().token_type, TokenType::Keyword("SPAWN".to_string()));
-        assert_eq!(lexer.next_token().token_type, TokenType::Identifier("task_func".to_string()));
-        assert_eq!(lexer.next_token().token_type, TokenType::Keyword("WITH".to_string())); // "WITH" anahtar kelime olarak eklenebilir
-        assert_eq!(lexer.next_token().token_type, TokenType::Identifier("prio".to_string()));
-        assert_eq!(lexer.next_token().token_type, TokenType::Equals);
-        assert_eq!(lexer.next_token().token_type, TokenType::Number(2));
-        assert_eq!(lexer.next_token().token_type, TokenType::Keyword("ACQUIRE".to_string()));
-        assert_eq!(lexer.next_token().token_type, TokenType::StringLiteral("my_resource".to_string()));
-        assert_eq!(lexer.next_token().token_type, TokenType::Keyword("AS".to_string()));
-        assert_eq!(lexer.next_token().token_type, TokenType::Identifier("res1".to_string()));
-        assert_eq!(lexer.next_token().token_type, TokenType::Keyword("SEND".to_string()));
-        assert_eq!(lexer.next_token().token_type, TokenType::Identifier("handle1".to_string()));
-        assert_eq!(lexer.next_token().token_type, TokenType::Comma);
-        assert_eq!(lexer.next_token().token_type, TokenType::Identifier("message".to_string()));
-        assert_eq!(lexer.next_token().token_type, TokenType::EndOfFile);
-    }
-}
\ No newline at end of file
+use crate::keywords::{self, CaseSensitivity, WordKind};
+use crate::unicode;
+use crate::messages::{msg, Arg};
+use std::fmt;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TokenType {
+    Keyword(String),     // JUMP, FLAG, ALLOCATE vb.
+    Mnemonic(String),    // ADD, CALL, RET vb. genel komutlar
+    Identifier(String),  // Değişken, etiket, prosedür adı
+    Number(i64),         // 42, -7, 0xFF, 0b1010, 0o755, 'A' (64 bitlik desen olarak)
+    InvalidNumber(String, NumberError), // Kaynaktaki metin ve neden geçersiz olduğu
+    Flag(String),        // ZF, CF vb.
+    Handle(usize),       // Sahne64 Handle (sayısal olarak temsil edilebilir)
+    TaskId(usize),       // Sahne64 Task ID (sayısal olarak temsil edilebilir)
+    ResourceId(String),  // Kaynak adı (string)
+    Register(usize),     // R0, R1, ... (geçerli aralık hedefe bağlıdır)
+    Colon,               // :
+    Comma,               // ,
+    OpenParen,           // (
+    CloseParen,          // )
+    OpenBracket,         // [ (bellek başvurusu)
+    CloseBracket,        // ]
+    Equals,              // =
+    Plus,                // +
+    Minus,               // - (önek konumunda ve ardından rakam geliyorsa sayıya katılır)
+    Dollar,              // $ (adres alma: $etiket)
+    Directive(String),   // .data, .code, .global, .string vb. (noktasız ad)
+    LocationCounter,     // . (geçerli adres)
+    StringLiteral(String), // Örneğin kaynak adları için (kaçış dizileri çözülmüş olarak)
+    InvalidString(String, Vec<EscapeError>), // Geçersiz kaçış dizileri atlanmış değer ve hatalar
+    UnterminatedString(String), // Satır sonuna kadar kapatılmamış string
+    Comment(String),     // ';' ile satır sonuna kadar süren yorum (yalnızca istenirse üretilir)
+    Newline,             // Deyimleri ayıran satır sonu (yalnızca istenirse üretilir)
+    EndOfFile,
+    Unknown(char),
+}
+
+// Sayı ve karakter sabitlerindeki hatalar; parser bunları tanılamaya çevirir
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum NumberError {
+    MissingDigits,         // "0x", "0b_"
+    InvalidDigit(char),    // "0b102", "12AB"
+    Overflow,              // 64 bite sığmıyor
+    CharacterLength,       // '' veya 'AB'
+    UnterminatedCharacter, // Satır sonuna kadar kapatılmamış 'A
+    Escape(EscapeErrorKind), // '\q'
+}
+
+// String içindeki geçersiz kaçış dizisi; sütun ve uzunluk yalnızca kaçışın kendisini kapsar
+#[derive(Debug, PartialEq, Clone)]
+pub struct EscapeError {
+    pub column: usize,
+    pub length: usize,
+    pub kind: EscapeErrorKind,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum EscapeErrorKind {
+    Unknown(char),     // \q
+    InvalidHex,        // \x ardından iki onaltılık basamak yok
+    HexOutOfRange,     // \x80 ve üstü; \x yalnızca ASCII karakterler içindir
+    InvalidUnicode,    // \u{...} biçimi bozuk
+    UnicodeOutOfRange, // \u{D800}, \u{110000} gibi karakter olmayan değerler
+}
+
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub token_type: TokenType,
+    pub line: usize,
+    pub column: usize,
+    pub length: usize, // Kaynakta kapladığı karakter sayısı (token'lar satır aşmaz)
+    pub span: Span,
+}
+
+// Derlenen kaynaklar arasında bir dosyanın sırası
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct FileId(pub usize);
+
+// Token'ın dosyası ve kaynak metindeki [start, end) bayt aralığı; &text[start..end]
+// token'ın yazıldığı metni verir
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    pub file: FileId,
+    pub start: usize,
+    pub end: usize,
+}
+
+// Kaynak dosyadaki bir konum (1'den başlayan satır ve sütun) ve
+// tanılamalarda altı çizilecek karakter sayısı
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SourceLocation {
+    pub line: usize,
+    pub column: usize,
+    pub length: usize,
+}
+
+impl Token {
+    pub fn location(&self) -> SourceLocation {
+        SourceLocation { line: self.line, column: self.column, length: self.length }
+    }
+}
+
+// Tanılama mesajlarında token'ları kullanıcıya gösterilecek biçimde yazar
+impl TokenType {
+    // Bir işlenenin son token'ı olabilir mi? Ardından gelen '-' çıkarma işlecidir
+    fn ends_operand(&self) -> bool {
+        matches!(
+            self,
+            TokenType::Identifier(_) | TokenType::Number(_) | TokenType::InvalidNumber(..) | TokenType::Flag(_) | TokenType::Handle(_) | TokenType::TaskId(_) | TokenType::ResourceId(_) | TokenType::Register(_) | TokenType::CloseParen | TokenType::CloseBracket | TokenType::LocationCounter | TokenType::StringLiteral(_) | TokenType::InvalidString(..) | TokenType::UnterminatedString(_)
+        )
+    }
+
+    // Tanılama mesajlarında token'ın adı; dosya sonu seçilen dile çevrilir
+    pub fn describe(&self) -> Arg {
+        match self {
+            TokenType::EndOfFile => Arg::Key("token.end-of-file"),
+            TokenType::Newline => Arg::Key("token.newline"),
+            other => Arg::Text(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for TokenType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenType::Keyword(value) | TokenType::Mnemonic(value) | TokenType::Identifier(value) | TokenType::Flag(value) | TokenType::ResourceId(value) => {
+                write!(f, "'{}'", value)
+            }
+            TokenType::Number(value) => write!(f, "'{}'", value),
+            TokenType::InvalidNumber(text, _) => write!(f, "'{}'", text),
+            TokenType::Handle(value) | TokenType::TaskId(value) => write!(f, "'{}'", value),
+            TokenType::Register(index) => write!(f, "'R{}'", index),
+            TokenType::Colon => write!(f, "':'"),
+            TokenType::Comma => write!(f, "','"),
+            TokenType::OpenParen => write!(f, "'('"),
+            TokenType::CloseParen => write!(f, "')'"),
+            TokenType::OpenBracket => write!(f, "'['"),
+            TokenType::CloseBracket => write!(f, "']'"),
+            TokenType::Equals => write!(f, "'='"),
+            TokenType::Plus => write!(f, "'+'"),
+            TokenType::Minus => write!(f, "'-'"),
+            TokenType::Dollar => write!(f, "'$'"),
+            TokenType::Directive(name) => write!(f, "'.{}'", name),
+            TokenType::LocationCounter => write!(f, "'.'"),
+            TokenType::StringLiteral(value) | TokenType::InvalidString(value, _) => write!(f, "\"{}\"", escape_string(value)),
+            TokenType::UnterminatedString(value) => write!(f, "\"{}", escape_string(value)),
+            TokenType::Comment(value) => write!(f, "';{}'", value),
+            TokenType::Newline => write!(f, "{}", msg!("token.newline")),
+            TokenType::EndOfFile => write!(f, "{}", msg!("token.end-of-file")),
+            TokenType::Unknown(value) => write!(f, "'{}'", value),
+        }
+    }
+}
+
+pub struct Lexer {
+    pub(crate) input: String,
+    pub(crate) position: usize, // input içindeki bayt konumu; her zaman bir karakter sınırındadır
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+    keep_comments: bool, // Biçimlendirici ve belge araçları yorumları token olarak ister
+    keep_newlines: bool, // Parser deyimlerin satır satır yazılmasını denetlemek için ister
+    file: FileId,
+    token_start: usize,  // Okunmakta olan token'ın başladığı bayt konumu
+    finished: bool,      // Iterator olarak EndOfFile verildi
+    case_sensitivity: CaseSensitivity, // Ayrılmış sözcüklerin ve register adlarının yazımı
+    after_operand: bool, // Aynı satırdaki önceki token bir işlenen bitirdi ("R1-4", ".-1")
+}
+
+impl Lexer {
+    pub fn new(input: String) -> Self {
+        Lexer {
+            input,
+            position: 0,
+            line: 1,
+            column: 1,
+            keep_comments: false,
+            keep_newlines: false,
+            file: FileId::default(),
+            token_start: 0,
+            finished: false,
+            case_sensitivity: CaseSensitivity::default(),
+            after_operand: false,
+        }
+    }
+
+    // Açıksa satır sonları atlanmak yerine TokenType::Newline olarak döndürülür
+    pub fn set_keep_newlines(&mut self, keep: bool) {
+        self.keep_newlines = keep;
+    }
+
+    // Token'ların Span'lerine yazılacak dosya
+    pub fn set_file(&mut self, file: FileId) {
+        self.file = file;
+    }
+
+    pub fn set_case_sensitivity(&mut self, case_sensitivity: CaseSensitivity) {
+        self.case_sensitivity = case_sensitivity;
+    }
+
+    // Açıksa yorumlar atlanmak yerine TokenType::Comment olarak döndürülür
+    pub fn set_keep_comments(&mut self, keep: bool) {
+        self.keep_comments = keep;
+    }
+
+    // Karakterler bayt konumundan okunur, böylece her adım sabit sürelidir ve
+    // büyük kaynaklar doğrusal sürede taranır
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn peek_next(&self) -> Option<char> {
+        self.input[self.position..].chars().nth(1)
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let current = self.peek();
+        if let Some(c) = current {
+            self.position += c.len_utf8();
+            self.column += 1; // Sütunlar bayt değil karakter sayar
+            Some(c)
+        } else {
+            None
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == '\n' && self.keep_newlines {
+                break;
+            }
+            if c.is_whitespace() {
+                if c == '\n' {
+                    self.line += 1;
+                    self.column = 0; // advance() sütunu 1'e getirir
+                    self.after_operand = false;
+                }
+                self.advance();
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub fn next_token(&mut self) -> Token {
+        self.skip_whitespace();
+        while self.peek() == Some(';') {
+            self.token_start = self.position;
+            let comment = self.comment();
+            if self.keep_comments {
+                return comment;
+            }
+            self.skip_whitespace();
+        }
+
+        self.token_start = self.position;
+        let token = match self.peek() {
+            Some(c) => {
+                match c {
+                    '\n' => self.newline(),
+                    ':' => self.single_char_token(TokenType::Colon),
+                    ',' => self.single_char_token(TokenType::Comma),
+                    '(' => self.single_char_token(TokenType::OpenParen),
+                    ')' => self.single_char_token(TokenType::CloseParen),
+                    '[' => self.single_char_token(TokenType::OpenBracket),
+                    ']' => self.single_char_token(TokenType::CloseBracket),
+                    '=' => self.single_char_token(TokenType::Equals),
+                    '+' => self.single_char_token(TokenType::Plus),
+                    '$' => self.single_char_token(TokenType::Dollar),
+                    // '-' yalnızca önek konumunda sayının işaretidir; "R1-4" ve ".-1" çıkarmadır
+                    '-' if self.after_operand || !self.peek_next().is_some_and(|next| next.is_ascii_digit()) => self.single_char_token(TokenType::Minus),
+                    '.' if self.peek_next().is_some_and(|next| next.is_alphabetic()) => self.directive(),
+                    '.' => self.single_char_token(TokenType::LocationCounter),
+                    '"' => self.string_literal(),
+                    '\'' => self.character(),
+                    'A'..='Z' | 'a'..='z' | '_' => self.identifier_or_keyword(),
+                    '0'..='9' | '-' => self.number(),
+                    c if unicode::is_identifier_start(c) => self.identifier_or_keyword(),
+                    other => {
+                        let start_column = self.column;
+                        self.advance();
+                        self.token(TokenType::Unknown(other), start_column)
+                    }
+                }
+            }
+            None => self.token(TokenType::EndOfFile, self.column),
+        };
+        self.after_operand = token.token_type.ends_operand();
+        token
+    }
+
+    fn single_char_token(&mut self, token_type: TokenType) -> Token {
+        let start_column = self.column;
+        self.advance();
+        self.token(token_type, start_column)
+    }
+
+    // start_column'dan geçerli konuma kadar uzanan token
+    fn token(&self, token_type: TokenType, start_column: usize) -> Token {
+        Token {
+            token_type,
+            line: self.line,
+            column: start_column,
+            length: self.column - start_column,
+            span: Span { file: self.file, start: self.token_start, end: self.position },
+        }
+    }
+
+    // Satır sonu token'ı bittiği satıra aittir
+    fn newline(&mut self) -> Token {
+        let token = self.single_char_token(TokenType::Newline);
+        self.line += 1;
+        self.column = 1;
+        token
+    }
+
+    // Harf veya '_' ile başlayıp harf, rakam, '_' ve birleştirici işaretlerle süren ad
+    // (bkz. unicode.rs); ayrılmış sözcükler keywords tablosundan sınıflandırılır
+    fn identifier_or_keyword(&mut self) -> Token {
+        let start_column = self.column;
+        let start_position = self.position;
+        while self.peek().is_some_and(unicode::is_identifier_continue) {
+            self.advance();
+        }
+        // Aynı görünen adlar aynı token'ı üretsin diye ad NFC biçimine getirilir
+        let identifier = unicode::nfc(&self.input[start_position..self.position]);
+        let token_type = match keywords::classify(&identifier, self.case_sensitivity) {
+            Some((name, WordKind::Keyword)) => TokenType::Keyword(name.to_string()),
+            Some((name, WordKind::Mnemonic)) => TokenType::Mnemonic(name.to_string()),
+            Some((name, WordKind::Flag)) => TokenType::Flag(name.to_string()),
+            None => match register_index(&identifier, self.case_sensitivity) {
+                Some(index) => TokenType::Register(index),
+                None => TokenType::Identifier(identifier.to_string()),
+            },
+        };
+        self.token(token_type, start_column)
+    }
+
+    // '.' ve ardından gelen ad; hangi direktiflerin geçerli olduğuna parser karar verir
+    fn directive(&mut self) -> Token {
+        let start_column = self.column;
+        self.advance(); // '.'
+        let mut name = String::new();
+        while let Some(ch) = self.peek() {
+            if ch.is_alphanumeric() || ch == '_' {
+                name.push(ch);
+                self.advance();
+            } else {
+                break;
+            }
+        }
+        self.token(TokenType::Directive(name), start_column)
+    }
+
+    // İsteğe bağlı '-' işareti, 0x/0b/0o ön eki ve '_' ayırıcılı basamaklar. Sayıya
+    // bitişik harfler de okunur ki "0b102" veya "12AB" tek bir geçersiz sabit olsun.
+    fn number(&mut self) -> Token {
+        let mut text = String::new();
+        let start_column = self.column;
+        let negative = self.peek() == Some('-');
+        if negative {
+            text.extend(self.advance());
+        }
+        let radix = match (self.peek(), self.peek_next()) {
+            (Some('0'), Some('x' | 'X')) => 16,
+            (Some('0'), Some('b' | 'B')) => 2,
+            (Some('0'), Some('o' | 'O')) => 8,
+            _ => 10,
+        };
+        if radix != 10 {
+            text.extend(self.advance());
+            text.extend(self.advance());
+        }
+        let digits_start = text.len();
+        while let Some(ch) = self.peek() {
+            if ch.is_alphanumeric() || ch == '_' {
+                text.push(ch);
+                self.advance();
+            } else {
+                break;
+            }
+        }
+        // Burada Handle veya TaskId olabilecek sayıları ayırt etmek için bağlama duyarlı analiz gerekebilir.
+        // Şimdilik sadece Number olarak kabul edelim ve Parser'da daha detaylı kontrol edelim.
+        let token_type = match parse_digits(&text[digits_start..], radix, negative) {
+            Ok(value) => TokenType::Number(value),
+            Err(error) => TokenType::InvalidNumber(text, error),
+        };
+        self.token(token_type, start_column)
+    }
+
+    // 'A' karakter sabiti; değeri karakterin Unicode kod noktasıdır
+    fn character(&mut self) -> Token {
+        let start_column = self.column;
+        let start_position = self.position;
+        self.advance(); // Açılış tırnağı
+        let mut characters = Vec::new();
+        let mut closed = false;
+        let mut escape_error = None;
+        while let Some(ch) = self.peek() {
+            match ch {
+                '\n' => break, // String'ler gibi karakter sabitleri de satır sonunu aşamaz
+                '\'' => {
+                    self.advance();
+                    closed = true;
+                    break;
+                }
+                '\\' => match self.escape() {
+                    Ok(ch) => characters.push(ch),
+                    Err(error) => escape_error = escape_error.or(Some(error.kind)),
+                },
+                _ => {
+                    characters.push(ch);
+                    self.advance();
+                }
+            }
+        }
+        let text = self.text_from(start_position);
+        let token_type = match (closed, escape_error, characters.as_slice()) {
+            (false, _, _) => TokenType::InvalidNumber(text, NumberError::UnterminatedCharacter),
+            (true, Some(kind), _) => TokenType::InvalidNumber(text, NumberError::Escape(kind)),
+            (true, None, [ch]) => TokenType::Number(*ch as i64),
+            (true, None, _) => TokenType::InvalidNumber(text, NumberError::CharacterLength),
+        };
+        self.token(token_type, start_column)
+    }
+
+    // ';' işaretinden satır sonuna kadar; satır sonu karakteri yoruma dahil değildir
+    fn comment(&mut self) -> Token {
+        let start_column = self.column;
+        self.advance(); // ';' işaretini atla
+        let mut text = String::new();
+        while let Some(ch) = self.peek() {
+            // CRLF satır sonlarında '\r' yorumun metnine katılmaz
+            if ch == '\n' || (ch == '\r' && self.peek_next() == Some('\n')) {
+                break;
+            }
+            text.push(ch);
+            self.advance();
+        }
+        self.token(TokenType::Comment(text), start_column)
+    }
+
+    fn string_literal(&mut self) -> Token {
+        let start_column = self.column;
+        self.advance(); // Açılış tırnağını atla
+        let mut value = String::new();
+        let mut errors = Vec::new();
+        while let Some(ch) = self.peek() {
+            match ch {
+                '\n' => break, // String'ler satır sonunu aşamaz; parser sonraki satırdan devam edebilsin
+                '"' => {
+                    self.advance();
+                    let token_type = match errors.is_empty() {
+                        true => TokenType::StringLiteral(value),
+                        false => TokenType::InvalidString(value, errors),
+                    };
+                    return self.token(token_type, start_column);
+                }
+                '\\' => match self.escape() {
+                    Ok(ch) => value.push(ch),
+                    Err(error) => errors.push(error),
+                },
+                _ => {
+                    value.push(ch);
+                    self.advance();
+                }
+            }
+        }
+        self.token(TokenType::UnterminatedString(value), start_column)
+    }
+
+    // '\\' ile başlayan kaçış dizisi. Hatalı dizi de sonuna kadar tüketilir, böylece
+    // string'in geri kalanı doğru okunur.
+    fn escape(&mut self) -> Result<char, EscapeError> {
+        let start_column = self.column;
+        self.advance(); // '\\'
+        let result = match self.peek() {
+            Some(ch @ ('n' | 't' | 'r' | '0' | '\\' | '"' | '\'')) => {
+                self.advance();
+                Ok(match ch {
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    '0' => '\0',
+                    other => other,
+                })
+            }
+            Some('x') => {
+                self.advance();
+                self.hex_escape()
+            }
+            Some('u') => {
+                self.advance();
+                self.unicode_escape()
+            }
+            // Satır sonundaki ters bölü; string zaten kapatılmamış olarak raporlanacak
+            None | Some('\n') => Ok('\\'),
+            Some(other) => {
+                self.advance();
+                Err(EscapeErrorKind::Unknown(other))
+            }
+        };
+        result.map_err(|kind| EscapeError { column: start_column, length: self.column - start_column, kind })
+    }
+
+    // \xNN: tam olarak iki onaltılık basamak, en fazla \x7F
+    fn hex_escape(&mut self) -> Result<char, EscapeErrorKind> {
+        let mut value = 0;
+        for _ in 0..2 {
+            match self.peek().and_then(|ch| ch.to_digit(16)) {
+                Some(digit) => {
+                    value = value * 16 + digit;
+                    self.advance();
+                }
+                None => return Err(EscapeErrorKind::InvalidHex),
+            }
+        }
+        match char::from_u32(value).filter(char::is_ascii) {
+            Some(ch) => Ok(ch),
+            None => Err(EscapeErrorKind::HexOutOfRange),
+        }
+    }
+
+    // \u{...}: süslü parantez içinde 1-6 onaltılık basamaklı Unicode kod noktası
+    fn unicode_escape(&mut self) -> Result<char, EscapeErrorKind> {
+        if self.peek() != Some('{') {
+            return Err(EscapeErrorKind::InvalidUnicode);
+        }
+        self.advance();
+        let mut digits = String::new();
+        while let Some(ch) = self.peek().filter(char::is_ascii_hexdigit) {
+            digits.push(ch);
+            self.advance();
+        }
+        if self.peek() != Some('}') || digits.is_empty() || digits.len() > 6 {
+            return Err(EscapeErrorKind::InvalidUnicode);
+        }
+        self.advance();
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or(EscapeErrorKind::UnicodeOutOfRange)
+    }
+
+    // start_position'dan geçerli konuma kadar kaynak metin
+    fn text_from(&self, start_position: usize) -> String {
+        self.input[start_position..self.position].to_string()
+    }
+}
+
+// String değerini kaynakta yazılacağı biçime geri çevirir; codegen çıktısı ve
+// tanılamalar tekrar okunabilir kalsın diye kontrol karakterleri kaçışla yazılır
+pub fn escape_string(value: &str) -> String {
+    let mut escaped = String::new();
+    for ch in value.chars() {
+        match ch {
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            '\0' => escaped.push_str("\\0"),
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            ch if ch.is_ascii_control() => escaped.push_str(&format!("\\x{:02X}", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+// Ön eki ayrılmış basamakların değeri. İşaretsiz sabitler 64 bitin tamamını
+// kullanabilir (0xFFFF_FFFF_FFFF_FFFF bit deseni olarak -1 saklanır); negatif
+// sabitler en fazla -2^63 olabilir.
+fn parse_digits(digits: &str, radix: u32, negative: bool) -> Result<i64, NumberError> {
+    let mut value: u64 = 0;
+    let mut any_digit = false;
+    for ch in digits.chars().filter(|&ch| ch != '_') {
+        let digit = ch.to_digit(radix).ok_or(NumberError::InvalidDigit(ch))?;
+        value = value
+            .checked_mul(radix as u64)
+            .and_then(|value| value.checked_add(digit as u64))
+            .ok_or(NumberError::Overflow)?;
+        any_digit = true;
+    }
+    if !any_digit {
+        return Err(NumberError::MissingDigits);
+    }
+    if !negative {
+        return Ok(value as i64);
+    }
+    if value > i64::MIN.unsigned_abs() {
+        return Err(NumberError::Overflow);
+    }
+    Ok(0u64.wrapping_sub(value) as i64)
+}
+
+// Token'ları sırayla verir; son öğe EndOfFile token'ıdır (konumu tanılamalarda
+// kullanılır), ardından None döner
+impl Iterator for Lexer {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        if self.finished {
+            return None;
+        }
+        let token = self.next_token();
+        self.finished = token.token_type == TokenType::EndOfFile;
+        Some(token)
+    }
+}
+
+// "R" ve ardından ondalık register numarası ("R0", "R12"); "R01" gibi baştaki
+// sıfırlar register sayılmaz. Büyük/küçük harfe duyarsız kuralda "r3" de kabul edilir.
+fn register_index(identifier: &str, case_sensitivity: CaseSensitivity) -> Option<usize> {
+    let (prefix, digits) = identifier.split_at_checked(1)?;
+    if !case_sensitivity.matches("R", prefix) {
+        return None;
+    }
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) || (digits.len() > 1 && digits.starts_with('0')) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lexer_with_sahne64() {
+        let input = "ALLOCATE 1024 AS handle1\nSPAWN task_func WITH prio=2\nACQUIRE \"my_resource\" AS res1\nSEND handle1, message";
+        let mut lexer = Lexer::new(input.to_string());
+
+        assert_eq!(lexer.next_token().token_type, TokenType::Keyword("ALLOCATE".to_string()));
+        assert_eq!(lexer.next_token().token_type, TokenType::Number(1024));
+        assert_eq!(lexer.next_token().token_type, TokenType::Keyword("AS".to_string())); // "AS" anahtar kelime olarak eklenebilir
+        assert_eq!(lexer.next_token().token_type, TokenType::Identifier("handle1".to_string()));
+        assert_eq!(lexer.next_token().token_type, TokenType::Keyword("SPAWN".to_string()));
+        assert_eq!(lexer.next_token().token_type, TokenType::Identifier("task_func".to_string()));
+        assert_eq!(lexer.next_token().token_type, TokenType::Keyword("WITH".to_string())); // "WITH" anahtar kelime olarak eklenebilir
+        assert_eq!(lexer.next_token().token_type, TokenType::Identifier("prio".to_string()));
+        assert_eq!(lexer.next_token().token_type, TokenType::Equals);
+        assert_eq!(lexer.next_token().token_type, TokenType::Number(2));
+        assert_eq!(lexer.next_token().token_type, TokenType::Keyword("ACQUIRE".to_string()));
+        assert_eq!(lexer.next_token().token_type, TokenType::StringLiteral("my_resource".to_string()));
+        assert_eq!(lexer.next_token().token_type, TokenType::Keyword("AS".to_string()));
+        assert_eq!(lexer.next_token().token_type, TokenType::Identifier("res1".to_string()));
+        assert_eq!(lexer.next_token().token_type, TokenType::Keyword("SEND".to_string()));
+        assert_eq!(lexer.next_token().token_type, TokenType::Identifier("handle1".to_string()));
+        assert_eq!(lexer.next_token().token_type, TokenType::Comma);
+        assert_eq!(lexer.next_token().token_type, TokenType::Identifier("message".to_string()));
+        assert_eq!(lexer.next_token().token_type, TokenType::EndOfFile);
+    }
+
+    #[test]
+    fn test_identifiers_and_case_sensitivity() {
+        let input = "spawn target_2 _tmp SEND CTRL OR ret zf Cf r3 R01 Ünite ışık_1 s\u{0327}ayaç";
+        let token_types = |case_sensitivity| {
+            let mut lexer = Lexer::new(input.to_string());
+            lexer.set_case_sensitivity(case_sensitivity);
+            let mut token_types = Vec::new();
+            loop {
+                match lexer.next_token().token_type {
+                    TokenType::EndOfFile => return token_types,
+                    token_type => token_types.push(token_type),
+                }
+            }
+        };
+
+        let keyword = |value: &str| TokenType::Keyword(value.to_string());
+        let mnemonic = |value: &str| TokenType::Mnemonic(value.to_string());
+        let flag = |value: &str| TokenType::Flag(value.to_string());
+        let identifier = |value: &str| TokenType::Identifier(value.to_string());
+        assert_eq!(token_types(CaseSensitivity::Insensitive), vec![
+            keyword("SPAWN"),
+            identifier("target_2"),
+            identifier("_tmp"),
+            keyword("SEND"),
+            keyword("CTRL"),
+            mnemonic("OR"),
+            mnemonic("RET"),
+            flag("ZF"),
+            flag("CF"),
+            TokenType::Register(3),
+            identifier("R01"),
+            identifier("Ünite"),
+            identifier("ışık_1"),
+            identifier("şayaç"),
+        ]);
+        assert_eq!(token_types(CaseSensitivity::Sensitive), vec![
+            identifier("spawn"),
+            identifier("target_2"),
+            identifier("_tmp"),
+            keyword("SEND"),
+            keyword("CTRL"),
+            mnemonic("OR"),
+            identifier("ret"),
+            identifier("zf"),
+            identifier("Cf"),
+            identifier("r3"),
+            identifier("R01"),
+            identifier("Ünite"),
+            identifier("ışık_1"),
+            identifier("şayaç"),
+        ]);
+    }
+
+    #[test]
+    fn test_spans_and_newline_tokens() {
+        let input = "MSG: .string \"Dünya\" ; yorum\r\n\n  RELEASE MSG";
+        let tokens = |keep_newlines| {
+            let mut lexer = Lexer::new(input.to_string());
+            lexer.set_file(FileId(2));
+            lexer.set_keep_newlines(keep_newlines);
+            let mut tokens = Vec::new();
+            loop {
+                let token = lexer.next_token();
+                if token.token_type == TokenType::EndOfFile {
+                    return tokens;
+                }
+                tokens.push(token);
+            }
+        };
+
+        let kept = tokens(true);
+        let summary: Vec<_> = kept.iter().map(|t| (t.token_type.clone(), t.line, t.column, &input[t.span.start..t.span.end])).collect();
+        assert_eq!(summary, vec![
+            (TokenType::Identifier("MSG".to_string()), 1, 1, "MSG"),
+            (TokenType::Colon, 1, 4, ":"),
+            (TokenType::Directive("string".to_string()), 1, 6, ".string"),
+            (TokenType::StringLiteral("Dünya".to_string()), 1, 14, "\"Dünya\""),
+            (TokenType::Newline, 1, 30, "\n"),
+            (TokenType::Newline, 2, 1, "\n"),
+            (TokenType::Keyword("RELEASE".to_string()), 3, 3, "RELEASE"),
+            (TokenType::Identifier("MSG".to_string()), 3, 11, "MSG"),
+        ]);
+        assert!(kept.iter().all(|t| t.span.file == FileId(2)));
+        assert_eq!(kept[3].span, Span { file: FileId(2), start: 13, end: 21 });
+        assert_eq!(tokens(false).len(), kept.len() - 2);
+    }
+
+    #[test]
+    fn test_lexer_iterator_ends_with_end_of_file() {
+        let mut lexer = Lexer::new("RELEASE 1".to_string());
+        let token_types: Vec<TokenType> = lexer.by_ref().map(|token| token.token_type).collect();
+        assert_eq!(token_types, vec![TokenType::Keyword("RELEASE".to_string()), TokenType::Number(1), TokenType::EndOfFile]);
+        assert!(lexer.next().is_none());
+    }
+
+    #[test]
+    fn test_line_comments() {
+        let input = "; Merhaba Dünya\nALLOCATE 16 AS MEM ; \"tampon\" ayır\n;\nRELEASE MEM;son";
+        let token_types = |keep_comments| {
+            let mut lexer = Lexer::new(input.to_string());
+            lexer.set_keep_comments(keep_comments);
+            let mut token_types = Vec::new();
+            loop {
+                let token = lexer.next_token();
+                if token.token_type == TokenType::EndOfFile {
+                    return token_types;
+                }
+                token_types.push((token.line, token.column, token.length, token.token_type));
+            }
+        };
+
+        let keyword = |value: &str| TokenType::Keyword(value.to_string());
+        let identifier = |value: &str| TokenType::Identifier(value.to_string());
+        let comment = |value: &str| TokenType::Comment(value.to_string());
+        assert_eq!(token_types(false), vec![
+            (2, 1, 8, keyword("ALLOCATE")),
+            (2, 10, 2, TokenType::Number(16)),
+            (2, 13, 2, keyword("AS")),
+            (2, 16, 3, identifier("MEM")),
+            (4, 1, 7, keyword("RELEASE")),
+            (4, 9, 3, identifier("MEM")),
+        ]);
+        let kept = token_types(true);
+        assert_eq!(kept[0], (1, 1, 15, comment(" Merhaba Dünya")));
+        assert_eq!(kept[5], (2, 20, 15, comment(" \"tampon\" ayır")));
+        assert_eq!(kept[6], (3, 1, 1, comment("")));
+        assert_eq!(kept[9], (4, 12, 4, comment("son")));
+
+        let mut lexer = Lexer::new("; satır\r\nRELEASE MEM ;son\r\n".to_string());
+        lexer.set_keep_comments(true);
+        let comments: Vec<(usize, TokenType)> = lexer.filter(|t| matches!(t.token_type, TokenType::Comment(_))).map(|t| (t.length, t.token_type)).collect();
+        assert_eq!(comments, vec![(7, comment(" satır")), (4, comment("son"))]);
+    }
+
+    #[test]
+    fn test_number_literals() {
+        let input = "0xFF 0b1010 0o755 1_000_000, -42 0XdeadBEEF 'A' 'ç' 0xFFFF_FFFF_FFFF_FFFF\n-9223372036854775808 . -MSG -1";
+        let mut lexer = Lexer::new(input.to_string());
+        let mut token_types = Vec::new();
+        loop {
+            let token = lexer.next_token();
+            if token.token_type == TokenType::EndOfFile {
+                break;
+            }
+            token_types.push(token.token_type);
+        }
+        assert_eq!(token_types, vec![
+            TokenType::Number(255),
+            TokenType::Number(10),
+            TokenType::Number(0o755),
+            TokenType::Number(1_000_000),
+            TokenType::Comma,
+            TokenType::Number(-42),
+            TokenType::Number(0xDEADBEEF),
+            TokenType::Number('A' as i64),
+            TokenType::Number('ç' as i64),
+            TokenType::Number(-1),
+            TokenType::Number(i64::MIN),
+            TokenType::LocationCounter,
+            TokenType::Minus,
+            TokenType::Identifier("MSG".to_string()),
+            TokenType::Minus,
+            TokenType::Number(1),
+        ]);
+
+        let invalid = |input: &str| Lexer::new(input.to_string()).next_token().token_type;
+        assert_eq!(invalid("12-3"), TokenType::Number(12));
+        assert_eq!(invalid("0b12"), TokenType::InvalidNumber("0b12".to_string(), NumberError::InvalidDigit('2')));
+        assert_eq!(invalid("0o_"), TokenType::InvalidNumber("0o_".to_string(), NumberError::MissingDigits));
+        assert_eq!(invalid("18446744073709551616"), TokenType::InvalidNumber("18446744073709551616".to_string(), NumberError::Overflow));
+        assert_eq!(invalid("-9223372036854775809"), TokenType::InvalidNumber("-9223372036854775809".to_string(), NumberError::Overflow));
+        assert_eq!(invalid("''"), TokenType::InvalidNumber("''".to_string(), NumberError::CharacterLength));
+    }
+
+    #[test]
+    fn test_string_escapes() {
+        let input = r#""Merhaba Dünya!\n" "\t\r\0\\\"\'" "\x41\u{1F600}\u{e7}" "a\qb\x8Fc\u{D800}" '\n' '\''"#;
+        let mut lexer = Lexer::new(input.to_string());
+        let mut next = || lexer.next_token().token_type;
+        assert_eq!(next(), TokenType::StringLiteral("Merhaba Dünya!\n".to_string()));
+        assert_eq!(next(), TokenType::StringLiteral("\t\r\0\\\"'".to_string()));
+        assert_eq!(next(), TokenType::StringLiteral("A😀ç".to_string()));
+        let escape = |column, length, kind| EscapeError { column, length, kind };
+        assert_eq!(next(), TokenType::InvalidString("abc".to_string(), vec![
+            escape(59, 2, EscapeErrorKind::Unknown('q')),
+            escape(62, 4, EscapeErrorKind::HexOutOfRange),
+            escape(67, 8, EscapeErrorKind::UnicodeOutOfRange),
+        ]));
+        assert_eq!(next(), TokenType::Number('\n' as i64));
+        assert_eq!(next(), TokenType::Number('\'' as i64));
+
+        assert_eq!(escape_string("\"a\\b\"\n\x01"), r#"\"a\\b\"\n\x01"#);
+        assert_eq!(Lexer::new(r#""yol\"#.to_string()).next_token().token_type, TokenType::UnterminatedString("yol\\".to_string()));
+    }
+}
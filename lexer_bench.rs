@@ -0,0 +1,38 @@
+// Lexer'ın çok megabaytlık girdilerdeki hızı. README'deki 29 MB'lık hedef kaynak
+// dosyası boyutuna kadar ölçer: `cargo bench --bench lexer`. Tarama doğrusal
+// olmalıdır; son satır en büyük ve en küçük girdinin MB başına süre oranıdır
+// (karakter konumundan yeniden tarayan bir lexer'da bu oran ~29'a çıkar).
+use bessambly::lexer::{Lexer, TokenType};
+use std::time::Instant;
+
+const SAMPLE: &str = "; Sahne64 örnek döngüsü
+.data
+MSG:     .string \"Merhaba Dünya!\\n\"
+MSG_LEN = . - MSG
+TABLE:   .byte 0x01, 0b1010, 'A'
+.code
+START:
+    ALLOCATE 0x400 AS MEM1
+    RELEASE $MSG
+    RELEASE R1
+    JUMP START
+";
+
+fn main() {
+    let mut seconds_per_megabyte = Vec::new();
+    for megabytes in [1, 4, 16, 29] {
+        let input = SAMPLE.repeat(megabytes * 1024 * 1024 / SAMPLE.len());
+        let start = Instant::now();
+        let mut lexer = Lexer::new(input.clone());
+        let mut tokens = 0usize;
+        while lexer.next_token().token_type != TokenType::EndOfFile {
+            tokens += 1;
+        }
+        let elapsed = start.elapsed();
+        let throughput = input.len() as f64 / (1024.0 * 1024.0) / elapsed.as_secs_f64();
+        println!("{:>3} MB: {:>9} token, {:>8.2?}, {:>7.1} MB/s", megabytes, tokens, elapsed, throughput);
+        seconds_per_megabyte.push(1.0 / throughput);
+    }
+    let ratio = seconds_per_megabyte[seconds_per_megabyte.len() - 1] / seconds_per_megabyte[0];
+    println!("MB başına süre oranı (29 MB / 1 MB): {:.2}", ratio);
+}
@@ -0,0 +1,35 @@
+// Bessambly derleyici kütüphanesi.
+// `bessc` komut satırı aracı ile aynı derleme hattını (lexer -> parser ->
+// semantik analiz -> bellek yerleşimi -> kod üretimi -> bağlama) diğer Rust
+// araçlarına açar.
+
+pub mod lexer;
+pub mod keywords;
+pub mod instructions;
+pub mod unicode;
+pub mod parser;
+pub mod macros;
+pub mod types;
+pub mod target;
+pub mod symbol_table;
+pub mod semantic_analyzer;
+pub mod control_flow;
+pub mod function_handle;
+pub mod variable_handler;
+pub mod operator_handler;
+pub mod io_handler;
+pub mod extern_symbols;
+pub mod memory_manager;
+pub mod codegen;
+pub mod linker;
+pub mod error_reporter;
+pub mod diagnostic_code;
+pub mod messages;
+pub mod file_provider;
+pub mod compiler;
+pub mod json;
+pub mod emit;
+
+pub use compiler::Compiler;
+pub use file_provider::{FileProvider, MemoryFileProvider, SourceFile};
+pub use target::Target;
@@ -1,19 +1,201 @@
-pub struct Linker {}
-
-impl Linker {
-    pub fn new() -> Self {
-        Linker {}
-    }
-
-    pub fn link(&self, object_code: &Vec<String>) -> Vec<String> {
-        // Basit bağlama işlemi (Sahne64 API çağrılarını olduğu gibi bırakır)
-        // Gerçek bir bağlayıcı, harici sembolleri (API fonksiyonları)
-        // Sahne64 sisteminde bilinen adreslere veya kütüphane çağrılarına
-        // dönüştürmesi gerekebilir. Bu, hedef platformun yürütme modeline bağlıdır.
-        println!("Bağlama işlemi (Sahne64 API çağrıları çözümlenmiyor)");
-        object_code.clone()
-    }
-
-    // İleride Sahne64 sistem çağrılarını ve kütüphane bağlantılarını
-    // ele alacak bir mekanizma eklenebilir.
-}
\ No newline at end of file
+use crate::diagnostic_code::DiagnosticCode;
+use crate::messages::{msg, Message};
+use std::collections::HashMap;
+
+// Tek bir kaynak dosyadan üretilen nesne kodu ve bağlayıcının ihtiyaç duyduğu sembol bilgileri
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectFile {
+    pub name: String,
+    pub code: Vec<String>,
+    pub definitions: Vec<String>, // Bu dosyada tanımlanan etiket ve prosedürler
+    pub globals: Vec<String>,     // GLOBAL ile dışa açılan semboller
+    pub externs: Vec<String>,     // EXTERN ile başka dosyalardan beklenen semboller
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkError {
+    pub object: String, // Hatanın ait olduğu nesne dosyası
+    pub code: DiagnosticCode,
+    pub message: Message,
+}
+
+pub struct Linker {}
+
+impl Default for Linker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Linker {
+    pub fn new() -> Self {
+        Linker {}
+    }
+
+    pub fn link(&self, object_code: &[String]) -> Vec<String> {
+        // Basit bağlama işlemi (Sahne64 API çağrılarını olduğu gibi bırakır)
+        // Gerçek bir bağlayıcı, harici sembolleri (API fonksiyonları)
+        // Sahne64 sisteminde bilinen adreslere veya kütüphane çağrılarına
+        // dönüştürmesi gerekebilir. Bu, hedef platformun yürütme modeline bağlıdır.
+        object_code.to_vec()
+    }
+
+    // Ayrı derlenmiş nesne dosyalarını tek bir çıktıda birleştirir. EXTERN
+    // semboller başka bir dosyanın GLOBAL tanımına bağlanır; dosyaya özel
+    // (GLOBAL olmayan) etiketler çakışırsa dosya sırasıyla yeniden adlandırılır.
+    pub fn link_objects(&self, objects: &[ObjectFile]) -> Result<Vec<String>, Vec<LinkError>> {
+        let mut errors = Vec::new();
+        let mut exported: HashMap<&str, &str> = HashMap::new(); // Sembol -> tanımlayan dosya
+
+        for object in objects {
+            for global in &object.globals {
+                if !object.definitions.contains(global) {
+                    errors.push(LinkError {
+                        object: object.name.clone(),
+                        code: DiagnosticCode::UndefinedGlobal,
+                        message: msg!("B0501", global),
+                    });
+                } else if let Some(previous) = exported.insert(global, &object.name) {
+                    errors.push(LinkError {
+                        object: object.name.clone(),
+                        code: DiagnosticCode::DuplicateGlobal,
+                        message: msg!("B0502", global, previous),
+                    });
+                }
+            }
+        }
+
+        for object in objects {
+            for external in &object.externs {
+                match exported.get(external.as_str()) {
+                    Some(owner) if *owner != object.name => {}
+                    _ => errors.push(LinkError {
+                        object: object.name.clone(),
+                        code: DiagnosticCode::UnresolvedExtern,
+                        message: msg!("B0503", external),
+                    }),
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        // Birden fazla dosyada tanımlanan yerel etiketleri belirle
+        let mut local_counts: HashMap<&str, usize> = HashMap::new();
+        for object in objects {
+            for definition in object.definitions.iter().filter(|d| !object.globals.contains(d)) {
+                *local_counts.entry(definition).or_insert(0) += 1;
+            }
+        }
+
+        let mut linked = Vec::new();
+        for (index, object) in objects.iter().enumerate() {
+            let renamed: Vec<&String> = object
+                .definitions
+                .iter()
+                .filter(|d| !object.globals.contains(d))
+                .filter(|d| local_counts[d.as_str()] > 1 || exported.contains_key(d.as_str()))
+                .collect();
+            for line in &object.code {
+                let mut line = line.clone();
+                for name in &renamed {
+                    line = rename_symbol(&line, name, &format!("{}.{}", name, index));
+                }
+                linked.push(line);
+            }
+        }
+        Ok(linked)
+    }
+
+    // İleride Sahne64 sistem çağrılarını ve kütüphane bağlantılarını
+    // ele alacak bir mekanizma eklenebilir.
+}
+
+// Satırdaki `from` sembolünün tam kelime eşleşmelerini değiştirir; string sabitlerine ve
+// `;` yorumlarına dokunmaz. "MSG.len" gibi noktayla devam eden kelimede ilk parça eşleşir
+fn rename_symbol(line: &str, from: &str, to: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut word = String::new();
+    let mut in_string = false;
+
+    let push_word = |result: &mut String, word: &mut String| {
+        match word.split_once('.') {
+            Some((head, rest)) if head == from => {
+                result.push_str(to);
+                result.push('.');
+                result.push_str(rest);
+            }
+            _ => result.push_str(if word == from { to } else { word }),
+        }
+        word.clear();
+    };
+
+    for (index, ch) in line.char_indices() {
+        if !in_string && (ch.is_alphanumeric() || ch == '_' || ch == '.') {
+            word.push(ch);
+            continue;
+        }
+        push_word(&mut result, &mut word);
+        if !in_string && ch == ';' {
+            result.push_str(&line[index..]);
+            return result;
+        }
+        if ch == '"' {
+            in_string = !in_string;
+        }
+        result.push(ch);
+    }
+    push_word(&mut result, &mut word);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(name: &str, code: &[&str], definitions: &[&str], globals: &[&str], externs: &[&str]) -> ObjectFile {
+        let strings = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        ObjectFile {
+            name: name.to_string(),
+            code: strings(code),
+            definitions: strings(definitions),
+            globals: strings(globals),
+            externs: strings(externs),
+        }
+    }
+
+    #[test]
+    fn test_link_objects_resolves_externs_and_renames_locals() {
+        let objects = [
+            object("a.b64", &["START:", "JUMP LOOP", "LOOP:"], &["START", "LOOP"], &["START"], &[]),
+            object("b.b64", &["LOOP:", "JUMP START", "JUMP LOOP"], &["LOOP"], &[], &["START"]),
+        ];
+        let linked = Linker::new().link_objects(&objects).unwrap();
+        assert_eq!(linked, vec!["START:", "JUMP LOOP.0", "LOOP.0:", "LOOP.1:", "JUMP START", "JUMP LOOP.1"]);
+    }
+
+    #[test]
+    fn test_rename_symbol_skips_strings_and_comments() {
+        assert_eq!(rename_symbol("JUMP LOOP ; LOOP tekrar", "LOOP", "LOOP.1"), "JUMP LOOP.1 ; LOOP tekrar");
+        assert_eq!(rename_symbol("SEND H, \"LOOP;\" ; LOOP", "LOOP", "LOOP.1"), "SEND H, \"LOOP;\" ; LOOP");
+        assert_eq!(rename_symbol("LOAD R1, MSG.len", "MSG", "MSG.0"), "LOAD R1, MSG.0.len");
+        assert_eq!(rename_symbol(".byte 1, byte", "byte", "byte.0"), ".byte 1, byte.0");
+    }
+
+    #[test]
+    fn test_link_objects_reports_unresolved_and_duplicate_globals() {
+        let objects = [
+            object("a.b64", &[], &["START"], &["START"], &["MISSING"]),
+            object("b.b64", &[], &["START"], &["START", "UNDEFINED"], &[]),
+        ];
+        let errors = Linker::new().link_objects(&objects).unwrap_err();
+        let messages: Vec<String> = errors.iter().map(|e| e.message.to_string()).collect();
+        assert_eq!(messages, vec![
+            "GLOBAL sembol 'START' zaten 'a.b64' dosyasında tanımlı",
+            "GLOBAL sembol 'UNDEFINED' bu dosyada tanımlı değil",
+            "Çözümlenemeyen harici sembol 'MISSING'",
+        ]);
+    }
+}
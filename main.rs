@@ -0,0 +1,298 @@
+use bessambly::compiler::CompileResult;
+use bessambly::emit::{self, EmitFormat, EmitKind};
+use bessambly::diagnostic_code::DiagnosticCode;
+use bessambly::error_reporter::{Lint, LintLevel, MessageFormat};
+use bessambly::keywords::CaseSensitivity;
+use bessambly::messages::{self, Arg, Language, Message};
+use bessambly::{Compiler, FileProvider, SourceFile};
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::Path;
+use std::process::{self, Command};
+
+// Çıkış kodları: derleme betikleri başarıyı, kaynak hatalarını ve G/Ç
+// hatalarını birbirinden ayırt edebilmeli
+const EXIT_SUCCESS: i32 = 0;
+const EXIT_DIAGNOSTICS: i32 = 1;
+const EXIT_IO_ERROR: i32 = 2;
+const EXIT_USAGE: i32 = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Subcommand {
+    Build,
+    Check,
+    Run,
+    Emit,
+}
+
+struct Options {
+    subcommand: Subcommand,
+    inputs: Vec<String>,
+    output: Option<String>,
+    emit: Vec<EmitKind>,
+    emit_format: EmitFormat,
+    message_format: MessageFormat,
+    lint_levels: Vec<(Lint, LintLevel)>, // Komut satırı sırasıyla; sonraki ayar öncekini ezer
+    warnings_as_errors: bool,
+    case_sensitivity: CaseSensitivity,
+    language: Language,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    // Argüman hataları da seçilen dilde yazılabilsin diye dil önceden belirlenir
+    let language = requested_language(&args).unwrap_or_else(Language::from_env);
+    let options = match parse_args(&args, language) {
+        Ok(Some(options)) => options,
+        Ok(None) => process::exit(EXIT_SUCCESS),
+        Err(message) => {
+            eprintln!("bessc: {}", message.text(language));
+            eprintln!("{}", messages::lookup(language, "driver.usage"));
+            process::exit(EXIT_USAGE);
+        }
+    };
+    process::exit(run(&options));
+}
+
+// Derleyicinin dosya erişimini gerçek dosya sistemine yönlendirir
+struct DiskFileProvider;
+
+impl FileProvider for DiskFileProvider {
+    fn read_source(&self, name: &str) -> io::Result<SourceFile> {
+        let text = fs::read_to_string(name).map_err(|error| io::Error::new(error.kind(), format!("'{}': {}", name, error)))?;
+        Ok(SourceFile { name: name.to_string(), text })
+    }
+
+    fn write_output(&mut self, name: &str, lines: &[String]) -> io::Result<()> {
+        let mut output_file = io::BufWriter::new(fs::File::create(name)?);
+        for line in lines {
+            writeln!(output_file, "{}", line)?;
+        }
+        output_file.flush()
+    }
+}
+
+// Komut satırındaki son geçerli "--lang" değeri
+fn requested_language(args: &[String]) -> Option<Language> {
+    let mut language = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = match arg.strip_prefix("--lang") {
+            Some("") => iter.next().map(|value| value.as_str()),
+            Some(value) => value.strip_prefix('='),
+            None => None,
+        };
+        language = value.and_then(Language::from_name).or(language);
+    }
+    language
+}
+
+// Sürücü mesajı; argümanlar olduğu gibi yazılır
+fn message(key: &'static str, args: &[&str]) -> Message {
+    Message::new(key, args.iter().map(Arg::from).collect())
+}
+
+fn parse_args(args: &[String], language: Language) -> Result<Option<Options>, Message> {
+    let mut subcommand = None;
+    let mut inputs = Vec::new();
+    let mut output = None;
+    let mut emit = Vec::new();
+    let mut emit_format = EmitFormat::Text;
+    let mut message_format = MessageFormat::Human;
+    let mut lint_levels = Vec::new();
+    let mut warnings_as_errors = false;
+    let mut case_sensitivity = CaseSensitivity::Insensitive;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        // "--secenek=deger" biçimini "--secenek deger" ile aynı ele al
+        let (arg, inline_value) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        match arg {
+            "-h" | "--help" => {
+                println!("{}", messages::lookup(language, "driver.usage"));
+                return Ok(None);
+            }
+            "-V" | "--version" => {
+                println!("bessc {}", env!("CARGO_PKG_VERSION"));
+                return Ok(None);
+            }
+            "--explain" => {
+                let code = inline_value.or_else(|| iter.next().cloned()).ok_or_else(|| message("driver.missing-code", &[arg]))?;
+                let code = DiagnosticCode::from_code(&code).ok_or_else(|| message("driver.unknown-code", &[&code]))?;
+                print!("{}", code.explain(language));
+                return Ok(None);
+            }
+            "-o" => match iter.next() {
+                Some(path) => output = Some(path.clone()),
+                None => return Err(message("driver.missing-file", &[arg])),
+            },
+            "--emit" => {
+                let kinds = inline_value.or_else(|| iter.next().cloned()).ok_or_else(|| message("driver.missing-emit-kind", &[arg]))?;
+                for name in kinds.split(',') {
+                    emit.push(EmitKind::from_name(name).ok_or_else(|| message("driver.unknown-value", &[arg, name]))?);
+                }
+            }
+            "--emit-format" => {
+                emit_format = match inline_value.or_else(|| iter.next().cloned()).as_deref() {
+                    Some("text") => EmitFormat::Text,
+                    Some("json") => EmitFormat::Json,
+                    Some(other) => return Err(message("driver.unknown-value", &[arg, other])),
+                    None => return Err(message("driver.missing-value", &[arg, "'text'", "'json'"])),
+                };
+            }
+            "--message-format" => {
+                message_format = match inline_value.or_else(|| iter.next().cloned()).as_deref() {
+                    Some("human") => MessageFormat::Human,
+                    Some("json") => MessageFormat::Json,
+                    Some(other) => return Err(message("driver.unknown-value", &[arg, other])),
+                    None => return Err(message("driver.missing-value", &[arg, "'human'", "'json'"])),
+                };
+            }
+            // Dil requested_language ile önceden seçildi; burada yalnızca doğrulanır
+            "--lang" => match inline_value.or_else(|| iter.next().cloned()) {
+                Some(name) if Language::from_name(&name).is_some() => {}
+                Some(other) => return Err(message("driver.unknown-value", &[arg, &other])),
+                None => return Err(message("driver.missing-value", &[arg, "'tr'", "'en'"])),
+            },
+            "--case-sensitive" => case_sensitivity = CaseSensitivity::Sensitive,
+            "-Werror" => warnings_as_errors = true,
+            "-A" | "-W" | "-D" => {
+                let level = match arg {
+                    "-A" => LintLevel::Allow,
+                    "-W" => LintLevel::Warn,
+                    _ => LintLevel::Deny,
+                };
+                let name = iter.next().ok_or_else(|| message("driver.missing-lint", &[arg]))?;
+                let lint = Lint::from_name(name).ok_or_else(|| message("driver.unknown-lint", &[name]))?;
+                lint_levels.push((lint, level));
+            }
+            other if other.starts_with('-') => return Err(message("driver.unknown-option", &[other])),
+            other if subcommand.is_none() => {
+                subcommand = Some(match other {
+                    "build" => Subcommand::Build,
+                    "check" => Subcommand::Check,
+                    "run" => Subcommand::Run,
+                    "emit" => Subcommand::Emit,
+                    _ => return Err(message("driver.unknown-command", &[other])),
+                });
+            }
+            other => inputs.push(other.to_string()),
+        }
+    }
+
+    let subcommand = subcommand.ok_or_else(|| message("driver.no-command", &[]))?;
+    if inputs.is_empty() {
+        return Err(message("driver.no-input", &[]));
+    }
+    if emit.is_empty() && subcommand == Subcommand::Emit {
+        emit.push(EmitKind::Link);
+    }
+    Ok(Some(Options {
+        subcommand,
+        inputs,
+        output,
+        emit,
+        emit_format,
+        message_format,
+        lint_levels,
+        warnings_as_errors,
+        case_sensitivity,
+        language,
+    }))
+}
+
+fn default_output(input: &str) -> String {
+    Path::new(input).with_extension("bs64").to_string_lossy().into_owned()
+}
+
+fn run(options: &Options) -> i32 {
+    let mut compiler = Compiler::new();
+    for (lint, level) in &options.lint_levels {
+        compiler.set_lint_level(*lint, *level);
+    }
+    compiler.set_warnings_as_errors(options.warnings_as_errors);
+    compiler.set_case_sensitivity(options.case_sensitivity);
+    let mut provider = DiskFileProvider;
+    let output = options.output.clone().unwrap_or_else(|| default_output(&options.inputs[0]));
+    let inputs: Vec<&str> = options.inputs.iter().map(|input| input.as_str()).collect();
+
+    let result = match options.subcommand {
+        Subcommand::Build | Subcommand::Run => compiler.compile(&mut provider, &inputs, &output),
+        Subcommand::Check => compiler.check(&provider, &inputs),
+        Subcommand::Emit => emit(&mut compiler, &mut provider, &inputs, options),
+    };
+
+    let result = match result {
+        Ok(result) => result,
+        Err(error) => {
+            eprintln!("bessc: {}", io_error_text(&error, options.language));
+            return EXIT_IO_ERROR;
+        }
+    };
+    // Renkler yalnızca terminale yazarken ve NO_COLOR ayarlı değilken kullanılır
+    let color = io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none();
+    eprint!("{}", result.render_diagnostics(options.message_format, color, options.language));
+    // build/check/run için istenen ara çıktılar, derleme başarısız olsa da
+    // hata ayıklamaya yardımcı olması için standart çıktıya yazılır
+    if options.subcommand != Subcommand::Emit && !options.emit.is_empty() {
+        let text = emit::render(&options.emit, options.emit_format, &result, options.language);
+        if let Err(error) = io::stdout().lock().write_all(text.as_bytes()) {
+            eprintln!("bessc: {}", error);
+            return EXIT_IO_ERROR;
+        }
+    }
+    if !result.is_success() {
+        return EXIT_DIAGNOSTICS;
+    }
+
+    match options.subcommand {
+        Subcommand::Build => {
+            println!("{}", message("driver.build-succeeded", &[&output]).text(options.language));
+            EXIT_SUCCESS
+        }
+        Subcommand::Run => execute(&output, options.language),
+        Subcommand::Check | Subcommand::Emit => EXIT_SUCCESS,
+    }
+}
+
+// Dosya sağlayıcısının katalog mesajı taşıyan hataları seçilen dilde yazılır
+fn io_error_text(error: &io::Error, language: Language) -> String {
+    match error.get_ref().and_then(|inner| inner.downcast_ref::<Message>()) {
+        Some(message) => message.text(language),
+        None => error.to_string(),
+    }
+}
+
+fn emit(compiler: &mut Compiler, provider: &mut DiskFileProvider, inputs: &[&str], options: &Options) -> io::Result<CompileResult> {
+    let sources = inputs.iter().map(|input| provider.read_source(input)).collect::<io::Result<Vec<_>>>()?;
+    let result = compiler.compile_sources(&sources);
+    let text = emit::render(&options.emit, options.emit_format, &result, options.language);
+    match &options.output {
+        Some(path) => provider.write_output(path, &text.lines().map(|line| line.to_string()).collect::<Vec<_>>())?,
+        None => io::stdout().lock().write_all(text.as_bytes())?,
+    }
+    Ok(result)
+}
+
+// .bs64 dosyaları Sahne64 üzerinde çalışır; ana makinede çalıştırmak için
+// BESSC_RUNNER ortam değişkeniyle bir yükleyici/emülatör belirtilmelidir
+fn execute(output: &str, language: Language) -> i32 {
+    let runner = match env::var("BESSC_RUNNER") {
+        Ok(runner) => runner,
+        Err(_) => {
+            eprintln!("bessc: {}", messages::lookup(language, "driver.runner-not-set"));
+            return EXIT_IO_ERROR;
+        }
+    };
+    match Command::new(&runner).arg(output).status() {
+        Ok(status) => status.code().unwrap_or(EXIT_IO_ERROR),
+        Err(error) => {
+            eprintln!("bessc: {}", message("driver.runner-failed", &[&runner, &error.to_string()]).text(language));
+            EXIT_IO_ERROR
+        }
+    }
+}
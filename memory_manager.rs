@@ -1,80 +1,151 @@
-use std::collections::HashMap;
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum MemorySection {
-    Static,
-    // Stack ve Heap yönetimi Sahne64 API'si üzerinden yapılabilir
-    Handle, // Handle'ları takip etmek için ayrı bir bölüm
-}
-
-#[derive(Debug, Clone)]
-pub struct MemoryAllocation {
-    pub section: MemorySection,
-    pub size: usize,
-    pub address: usize, // Sanal adres (derleyici için) veya handle değeri
-    // Diğer meta bilgiler (değişken adı vb.) eklenebilir
-}
-
-pub struct MemoryManager {
-    static_allocations: HashMap<String, MemoryAllocation>, // Değişken adı -> Tahsisat
-    handle_allocations: HashMap<String, MemoryAllocation>, // Handle adı -> Tahsisat (sembolik takip)
-    next_static_address: usize,
-    // Stack ve Heap boyutları/yönetimi derleyici tarafından doğrudan yapılmayabilir
-}
-
-impl MemoryManager {
-    pub fn new(static_base: usize) -> Self {
-        MemoryManager {
-            static_allocations: HashMap::new(),
-            handle_allocations: HashMap::new(),
-            next_static_address: static_base,
-        }
-    }
-
-    pub fn allocate_static(&mut self, name: &str, size: usize) -> Result<MemoryAllocation, String> {
-        if self.static_allocations.contains_key(name) {
-            return Err(format!("Statik değişken '{}' zaten tanımlı", name));
-        }
-        let allocation = MemoryAllocation {
-            section: MemorySection::Static,
-            size,
-            address: self.next_static_address,
-        };
-        self.static_allocations.insert(name.to_string(), allocation.clone());
-        self.next_static_address += size;
-        Ok(allocation)
-    }
-
-    pub fn get_static_allocation(&self, name: &str) -> Option<&MemoryAllocation> {
-        self.static_allocations.get(name)
-    }
-
-    // Handle yönetimi (sembolik olarak)
-    pub fn allocate_handle(&mut self, name: &str) -> Result<MemoryAllocation, String> {
-        if self.handle_allocations.contains_key(name) {
-            return Err(format!("Handle '{}' zaten tanımlı", name));
-        }
-        let allocation = MemoryAllocation {
-            section: MemorySection::Handle,
-            size: std::mem::size_of::<usize>(), // Handle boyutu platforma bağlı
-            address: 0, // Gerçek değeri Sahne64 atayacak
-        };
-        self.handle_allocations.insert(name.to_string(), allocation.clone());
-        Ok(allocation)
-    }
-
-    pub fn get_handle_allocation(&self, name: &str) -> Option<&MemoryAllocation> {
-        self.handle_allocations.get(name)
-    }
-
-    // Handle'ı serbest bırakma (sembolik takip)
-    pub fn release_handle(&mut self, name: &str) -> Result<(), String> {
-        if !self.handle_allocations.contains_key(name) {
-            return Err(format!("Tanımsız handle '{}'", name));
-        }
-        self.handle_allocations.remove(name);
-        Ok(())
-    }
-
-    // İleride sembol tablosu ile entegrasyon gerekebilir
+use crate::diagnostic_code::DiagnosticCode;
+use crate::messages::{msg, Message};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MemorySection {
+    Static,
+    Data, // .data bölümündeki etiketler; boyut, ardından gelen veri direktiflerinden oluşur
+    // Stack ve Heap yönetimi Sahne64 API'si üzerinden yapılabilir
+    Handle, // Handle'ları takip etmek için ayrı bir bölüm
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryAllocation {
+    pub section: MemorySection,
+    pub size: usize,
+    pub address: usize, // Sanal adres (derleyici için) veya handle değeri
+    // Diğer meta bilgiler (değişken adı vb.) eklenebilir
+}
+
+// Yerleşim hatası; derleyici bunu deyimin konumuyla tanılamaya çevirir
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryError {
+    pub code: DiagnosticCode,
+    pub message: Message,
+}
+
+impl MemoryError {
+    fn new(code: DiagnosticCode, message: Message) -> Self {
+        MemoryError { code, message }
+    }
+}
+
+pub struct MemoryManager {
+    static_allocations: HashMap<String, MemoryAllocation>, // Değişken adı -> Tahsisat
+    handle_allocations: HashMap<String, MemoryAllocation>, // Handle adı -> Tahsisat (sembolik takip)
+    next_static_address: usize,
+    open_data_label: Option<String>, // Veri direktiflerinin boyutunu eklediği son veri etiketi
+    // Stack ve Heap boyutları/yönetimi derleyici tarafından doğrudan yapılmayabilir
+}
+
+impl MemoryManager {
+    pub fn new(static_base: usize) -> Self {
+        MemoryManager {
+            static_allocations: HashMap::new(),
+            handle_allocations: HashMap::new(),
+            next_static_address: static_base,
+            open_data_label: None,
+        }
+    }
+
+    pub fn allocate_static(&mut self, name: &str, size: usize) -> Result<MemoryAllocation, MemoryError> {
+        self.open_data_label = None;
+        self.allocate(name, size, MemorySection::Static)
+    }
+
+    // Veri etiketi o anki adreste sıfır boyutla başlar; reserve_data ile büyür
+    pub fn allocate_data(&mut self, name: &str) -> Result<MemoryAllocation, MemoryError> {
+        let allocation = self.allocate(name, 0, MemorySection::Data)?;
+        self.open_data_label = Some(name.to_string());
+        Ok(allocation)
+    }
+
+    // Veri direktifinin ürettiği baytlar; açık bir veri etiketi varsa boyutuna eklenir
+    pub fn reserve_data(&mut self, size: usize) {
+        if let Some(allocation) = self.open_data_label.as_ref().and_then(|name| self.static_allocations.get_mut(name)) {
+            allocation.size += size;
+        }
+        self.next_static_address += size;
+    }
+
+    // Veri bölümü bittiğinde sonraki direktifler artık etikete eklenmez
+    pub fn close_data_label(&mut self) {
+        self.open_data_label = None;
+    }
+
+    fn allocate(&mut self, name: &str, size: usize, section: MemorySection) -> Result<MemoryAllocation, MemoryError> {
+        if self.static_allocations.contains_key(name) {
+            return Err(MemoryError::new(DiagnosticCode::DuplicateStatic, msg!("B0401", name)));
+        }
+        let allocation = MemoryAllocation {
+            section,
+            size,
+            address: self.next_static_address,
+        };
+        self.static_allocations.insert(name.to_string(), allocation.clone());
+        self.next_static_address += size;
+        Ok(allocation)
+    }
+
+    pub fn get_static_allocation(&self, name: &str) -> Option<&MemoryAllocation> {
+        self.static_allocations.get(name)
+    }
+
+    // Handle yönetimi (sembolik olarak)
+    pub fn allocate_handle(&mut self, name: &str) -> Result<MemoryAllocation, MemoryError> {
+        if self.handle_allocations.contains_key(name) {
+            return Err(MemoryError::new(DiagnosticCode::DuplicateHandle, msg!("B0402", name)));
+        }
+        let allocation = MemoryAllocation {
+            section: MemorySection::Handle,
+            size: std::mem::size_of::<usize>(), // Handle boyutu platforma bağlı
+            address: 0, // Gerçek değeri Sahne64 atayacak
+        };
+        self.handle_allocations.insert(name.to_string(), allocation.clone());
+        Ok(allocation)
+    }
+
+    pub fn get_handle_allocation(&self, name: &str) -> Option<&MemoryAllocation> {
+        self.handle_allocations.get(name)
+    }
+
+    // Handle'ı serbest bırakma (sembolik takip)
+    pub fn release_handle(&mut self, name: &str) -> Result<(), MemoryError> {
+        if !self.handle_allocations.contains_key(name) {
+            return Err(MemoryError::new(DiagnosticCode::UndefinedHandle, msg!("B0403", name)));
+        }
+        self.handle_allocations.remove(name);
+        Ok(())
+    }
+
+    // Bir sonraki statik tahsisatın adresi (sonraki dosyanın yerleşimi buradan başlar)
+    pub fn next_static_address(&self) -> usize {
+        self.next_static_address
+    }
+
+    // Statik tahsisatları adres sırasına göre döndürür (bellek haritası için)
+    pub fn static_allocations(&self) -> Vec<(&String, &MemoryAllocation)> {
+        let mut allocations: Vec<_> = self.static_allocations.iter().collect();
+        allocations.sort_by_key(|(_, allocation)| allocation.address);
+        allocations
+    }
+
+    // Handle kayıtlarını ada göre sıralı döndürür
+    pub fn handle_allocations(&self) -> Vec<(&String, &MemoryAllocation)> {
+        let mut allocations: Vec<_> = self.handle_allocations.iter().collect();
+        allocations.sort_by_key(|(name, _)| *name);
+        allocations
+    }
+
+    // Tüm yerleşim: önce adres sırasıyla statik tahsisatlar, ardından handle'lar
+    pub fn layout(&self) -> Vec<(String, MemoryAllocation)> {
+        self.static_allocations()
+            .into_iter()
+            .chain(self.handle_allocations())
+            .map(|(name, allocation)| (name.clone(), allocation.clone()))
+            .collect()
+    }
+
+    // İleride sembol tablosu ile entegrasyon gerekebilir
 }
\ No newline at end of file
@@ -0,0 +1,796 @@
+use crate::diagnostic_code::DiagnosticCode;
+use std::env;
+use std::fmt;
+
+// Derleyicinin kullanıcıya gösterdiği metinlerin dili
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    Turkish,
+    English,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Turkish, Language::English];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::Turkish => "tr",
+            Language::English => "en",
+        }
+    }
+
+    // "tr", "en" ya da "en_US.UTF-8" gibi bir yerel ayar adı
+    pub fn from_name(name: &str) -> Option<Language> {
+        let language = name.split(['_', '-', '.', '@']).next().unwrap_or_default();
+        Language::ALL.iter().copied().find(|l| l.name().eq_ignore_ascii_case(language))
+    }
+
+    // POSIX önceliğiyle LC_ALL, LC_MESSAGES ve LANG'den ilk ayarlı olan belirler;
+    // desteklenmeyen yerel ayarlar (ör. "C") varsayılan dile düşer
+    pub fn from_env() -> Language {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|variable| env::var(variable).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Language::from_name(&value))
+            .unwrap_or_default()
+    }
+}
+
+// Mesaj şablonundaki bir yer tutucunun değeri: olduğu gibi yazılan metin ya da
+// dile göre çevrilen başka bir katalog girdisi (ör. "dosya sonu")
+#[derive(Debug, Clone, PartialEq)]
+pub enum Arg {
+    Text(String),
+    Key(&'static str),
+}
+
+impl<T: fmt::Display> From<T> for Arg {
+    fn from(value: T) -> Self {
+        Arg::Text(value.to_string())
+    }
+}
+
+// Katalog anahtarı ve şablon argümanları; metin ancak gösterilirken seçilen
+// dilde oluşturulur, böylece tanılamalar dilden bağımsız veri olarak kalır
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub key: &'static str,
+    pub args: Vec<Arg>,
+}
+
+impl Message {
+    pub fn new(key: &'static str, args: Vec<Arg>) -> Self {
+        Message { key, args }
+    }
+
+    pub fn text(&self, language: Language) -> String {
+        let mut text = lookup(language, self.key).to_string();
+        for (index, arg) in self.args.iter().enumerate() {
+            let value = match arg {
+                Arg::Text(value) => value.as_str(),
+                Arg::Key(key) => lookup(language, key),
+            };
+            text = text.replace(&format!("{{{}}}", index), value);
+        }
+        text
+    }
+}
+
+// Varsayılan dilde
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text(Language::default()))
+    }
+}
+
+// Dosya sağlayıcıları io::Error içinde mesaj taşıyabilsin diye; çağıran
+// get_ref ile mesajı alıp istediği dilde yazabilir
+impl std::error::Error for Message {}
+
+// msg!("B0202", name) => Message { key: "B0202", args: [name] }
+macro_rules! msg {
+    ($key:expr $(, $arg:expr)* $(,)?) => {
+        $crate::messages::Message::new($key, vec![$($crate::messages::Arg::from($arg)),*])
+    };
+}
+pub(crate) use msg;
+
+// Anahtarın verilen dildeki şablonu. Bulunamayan anahtar olduğu gibi döner;
+// testler kaynaklarda kullanılan her anahtarın kataloğa girdiğini denetler
+pub fn lookup(language: Language, key: &str) -> &str {
+    match CATALOG.iter().find(|(k, _, _)| *k == key) {
+        Some((_, turkish, english)) => match language {
+            Language::Turkish => turkish,
+            Language::English => english,
+        },
+        None => key,
+    }
+}
+
+// Tanılama kodunun başlığı ve uzun açıklaması (`bessc --explain`)
+pub fn explanation(language: Language, code: DiagnosticCode) -> (&'static str, &'static str) {
+    let find = |suffix: &str| {
+        let key = format!("{}.{}", code.as_str(), suffix);
+        CATALOG.iter().find(|(k, _, _)| *k == key).map(|(_, turkish, english)| match language {
+            Language::Turkish => *turkish,
+            Language::English => *english,
+        })
+    };
+    (find("title").unwrap_or_default(), find("description").unwrap_or_default())
+}
+
+// (anahtar, Türkçe, İngilizce). Tanılama mesajlarının anahtarı tanılama kodudur;
+// aynı koda ait farklı mesajlar kodun ardından ".ad" ile ayrılır. Yer tutucular
+// {0}, {1}, ... sırasıyla Message argümanlarıyla doldurulur.
+const CATALOG: &[(&str, &str, &str)] = &[
+    // Sözdizimi
+    ("B0101", "Beklenmeyen karakter '{0}'", "unexpected character '{0}'"),
+    ("B0102", "Kapatılmamış string sabiti; satır sonundan önce '\"' bekleniyor", "unterminated string literal; expected '\"' before the end of the line"),
+    ("B0103", "{0} bekleniyor, {1} bulundu", "expected {0}, found {1}"),
+    ("B0103.instruction", "Bilinmeyen komut '{0}'", "unknown instruction '{0}'"),
+    ("B0104", "İfade bekleniyor, {0} bulundu", "expected an expression, found {0}"),
+    ("B0105.jump", "JUMP komutundan sonra hedef bekleniyor, {0} bulundu", "expected a jump target after JUMP, found {0}"),
+    ("B0105.flag", "FLAG komutundan sonra flag bekleniyor, {0} bulundu", "expected a flag after FLAG, found {0}"),
+    ("B0105.allocate", "ALLOCATE komutunda AS sonrasında handle adı bekleniyor, {0} bulundu", "expected a handle name after AS in ALLOCATE, found {0}"),
+    ("B0105.spawn", "SPAWN komutundan sonra prosedür adı bekleniyor, {0} bulundu", "expected a procedure name after SPAWN, found {0}"),
+    ("B0105.acquire", "ACQUIRE komutunda AS sonrasında handle adı bekleniyor, {0} bulundu", "expected a handle name after AS in ACQUIRE, found {0}"),
+    ("B0105.recv", "RECV komutunda virgülden sonra buffer adı bekleniyor, {0} bulundu", "expected a buffer name after the comma in RECV, found {0}"),
+    ("B0105.target", "{0} komutundan sonra hedef değişken adı bekleniyor, {1} bulundu", "expected a target variable name after {0}, found {1}"),
+    ("B0105.address", "'$' işaretinden sonra sembol adı bekleniyor, {0} bulundu", "expected a symbol name after '$', found {0}"),
+    ("B0105.macro", "MACRO anahtar kelimesinden sonra makro adı bekleniyor, {0} bulundu", "expected a macro name after MACRO, found {0}"),
+    ("B0105.directive", "{0} direktifinden sonra sembol adı bekleniyor, {1} bulundu", "expected a symbol name after the {0} directive, found {1}"),
+    ("B0106", "Bilinmeyen direktif '.{0}'", "unknown directive '.{0}'"),
+    ("B0107", "'{1}' sayı sabitinde geçersiz basamak '{0}'", "invalid digit '{0}' in number literal '{1}'"),
+    ("B0107.digits", "'{0}' sayı sabitinde basamak yok", "number literal '{0}' has no digits"),
+    ("B0107.character", "{0} karakter sabiti tam olarak bir karakter içermeli", "character literal {0} must contain exactly one character"),
+    ("B0107.unterminated", "Kapatılmamış karakter sabiti {0}; satır sonundan önce \"'\" bekleniyor", "unterminated character literal {0}; expected \"'\" before the end of the line"),
+    ("B0108", "'{0}' sayı sabiti 64 bite sığmıyor", "number literal '{0}' does not fit in 64 bits"),
+    ("B0109", "Bilinmeyen kaçış dizisi '\\{0}'", "unknown escape sequence '\\{0}'"),
+    ("B0109.hex", "'\\x' kaçışından sonra iki onaltılık basamak bekleniyor", "expected two hexadecimal digits after '\\x'"),
+    ("B0109.hex-range", "'\\x' kaçışı en fazla '\\x7F' olabilir", "'\\x' escapes must be at most '\\x7F'"),
+    ("B0109.unicode", "'\\u' kaçışı '\\u{1F600}' biçiminde 1-6 onaltılık basamak bekler", "'\\u' escapes expect 1-6 hexadecimal digits in the form '\\u{1F600}'"),
+    ("B0109.unicode-range", "'\\u' kaçışındaki değer geçerli bir Unicode karakteri değil", "the value of the '\\u' escape is not a valid Unicode character"),
+
+    // Adlar ve kapsamlar
+    ("B0110", "Deyimden sonra satır sonu bekleniyor, {0} bulundu", "expected end of line after the statement, found {0}"),
+    ("B0201", "Tanımsız değişken '{0}'", "undefined variable '{0}'"),
+    ("B0201.recv", "Tanımsız değişken '{0}' RECV komutunda buffer olarak kullanılıyor", "undefined variable '{0}' used as the RECV buffer"),
+    ("B0202", "Tanımsız etiket '{0}'", "undefined label '{0}'"),
+    ("B0203", "Tanımsız prosedür '{0}'", "undefined procedure '{0}'"),
+    ("B0204", "Sembol '{0}' zaten bu kapsamda tanımlı", "symbol '{0}' is already defined in this scope"),
+    ("B0204.variable", "Değişken '{0}' zaten bu kapsamda tanımlı", "variable '{0}' is already defined in this scope"),
+    ("B0204.procedure", "Prosedür '{0}' zaten tanımlı", "procedure '{0}' is already defined"),
+    ("B0204.macro", "Makro '{0}' zaten tanımlı", "macro '{0}' is already defined"),
+    ("B0205", "'{0}' hem GLOBAL hem EXTERN olarak bildirilemez", "'{0}' cannot be declared both GLOBAL and EXTERN"),
+    ("B0206", "Bilinmeyen tip '{0}'", "unknown type '{0}'"),
+
+    // Tipler ve işlenenler
+    ("B0301", "Tip uyuşmazlığı: '{0}' bekleniyordu, '{1}' bulundu", "mismatched types: expected '{0}', found '{1}'"),
+    ("B0301.number", "Tip uyuşmazlığı: Sayı beklenmiyordu", "mismatched types: a number was not expected"),
+    ("B0301.string", "Tip uyuşmazlığı: String beklenmiyordu", "mismatched types: a string was not expected"),
+    ("B0301.handle", "Tip uyuşmazlığı: Handle beklenmiyordu", "mismatched types: a handle was not expected"),
+    ("B0301.task-id", "Tip uyuşmazlığı: TaskId beklenmiyordu", "mismatched types: a task id was not expected"),
+    ("B0302", "Operand '{0}' sayısal bir tipte olmalı", "operand '{0}' must have a numeric type"),
+    ("B0302.value", "Operand sayısal bir değer veya değişken olmalı", "operand must be a numeric value or variable"),
+    ("B0303", "Operand karşılaştırılabilir bir değer, değişken veya flag olmalı", "operand must be a comparable value, variable or flag"),
+    ("B0303.string", "'{0}' bir string değişkeni; karşılaştırılamaz", "'{0}' is a string variable and cannot be compared"),
+    ("B0304", "Operand '{0}' mantıksal bir tipte olmalı (tamsayı)", "operand '{0}' must have a logical (integer) type"),
+    ("B0304.value", "Operand mantıksal bir değer, değişken veya flag olmalı (tamsayı)", "operand must be a logical (integer) value, variable or flag"),
+    ("B0305.var", "VAR komutu iki operand almalı (ad ve tip)", "VAR takes two operands (a name and a type)"),
+    ("B0305.instruction", "{0} komutu {1} işlenen alır, {2} verildi", "{0} takes {1} operand(s) but {2} were given"),
+    ("B0306.call", "CALL komutu geçerli bir prosedür adı almalı", "CALL requires a valid procedure name"),
+    ("B0306.assignment", "'{0}' atamanın sağ tarafında yalnızca ilk işlenen olabilir", "'{0}' may only be the first operand on the right-hand side of its assignment"),
+    ("B0306.var", "VAR komutu geçerli bir değişken adı ve tip adı almalı", "VAR requires a valid variable name and type name"),
+    ("B0306.instruction", "{0} komutunun {1}. işleneni {2} olmalı, {3} bulundu", "operand {1} of {0} must be {2}, found {3}"),
+    ("B0306.directive", "{0} direktifi bir tanımlayıcı almalı", "the {0} directive takes an identifier"),
+    ("B0306.api", "SAHNE64_API direktifi API adı ve ID almalı", "the SAHNE64_API directive takes an API name and an ID"),
+    ("B0307", "Sahne64 API hatası ({0}): {1}", "Sahne64 API error ({0}): {1}"),
+    ("B0307.ctrl-command", "kontrol komutu bir tamsayı kodu olmalı", "the control command must be an integer code"),
+    ("B0308", "'{0}' hedefinde 'R{1}' registerı yok; kullanılabilir registerlar R0-R{2}", "target '{0}' has no register 'R{1}'; available registers are R0-R{2}"),
+
+    // Bellek yerleşimi
+    ("B0401", "Statik değişken '{0}' zaten tanımlı", "static variable '{0}' is already allocated"),
+    ("B0402", "Handle '{0}' zaten tanımlı", "handle '{0}' is already defined"),
+    ("B0403", "Tanımsız handle '{0}'", "undefined handle '{0}'"),
+
+    // Bağlama
+    ("B0501", "GLOBAL sembol '{0}' bu dosyada tanımlı değil", "GLOBAL symbol '{0}' is not defined in this file"),
+    ("B0502", "GLOBAL sembol '{0}' zaten '{1}' dosyasında tanımlı", "GLOBAL symbol '{0}' is already defined in '{1}'"),
+    ("B0503", "Çözümlenemeyen harici sembol '{0}'", "unresolved external symbol '{0}'"),
+
+    // Uyarılar
+    ("B0601", "Etiket '{0}' hiç kullanılmıyor", "label '{0}' is never used"),
+    ("B0602", "'{0}' dış kapsamdaki aynı adlı sembolü gölgeliyor", "'{0}' shadows a symbol with the same name in an outer scope"),
+    ("B0603", "Sabit {0} '{1}' tipine sığmıyor ve kesilecek", "constant {0} does not fit in type '{1}' and will be truncated"),
+    ("B0604", "Ulaşılamaz kod", "unreachable code"),
+    ("B0605", "'{0}' zaten {1} olarak bildirildi", "'{0}' is already declared {1}"),
+    ("B0606", "'{0}' adı '{1}' ile karıştırılabilir", "'{0}' is easily confused with '{1}'"),
+
+    // İç hatalar
+    ("B0901", "Beklenmeyen AST kök düğümü", "unexpected AST root node"),
+    ("B0901.codegen-symbol", "'{0}' sembolü kod üretiminde sembol tablosunda bulunamadı", "symbol '{0}' was not found in the symbol table during code generation"),
+    ("B0901.extern-resolve", "Bildirilmemiş harici sembol '{0}' çözümlenmeye çalışıldı", "attempted to resolve undeclared external symbol '{0}'"),
+
+    // Yardım, etiket ve not satırları
+    ("help.undefined-variable", "değişkeni kullanmadan önce 'VAR <ad> <tip>' ile tanımlayın", "declare the variable with 'VAR <name> <type>' before using it"),
+    (
+        "help.undefined-label",
+        "etiket başka bir dosyada tanımlıysa 'EXTERN {0}' ile bildirin",
+        "if the label is defined in another file, declare it with 'EXTERN {0}'",
+    ),
+    (
+        "help.undefined-procedure",
+        "prosedür başka bir dosyada tanımlıysa 'EXTERN {0}' ile bildirin",
+        "if the procedure is defined in another file, declare it with 'EXTERN {0}'",
+    ),
+    ("help.rename-redefinition", "tanımlardan birini yeniden adlandırın", "rename one of the definitions"),
+    ("help.unreachable-code", "bu koda atlanabilmesi için önüne bir etiket ekleyin", "add a label before this code so that it can be jumped to"),
+    ("help.unused-label", "başka dosyalardan kullanılıyorsa 'GLOBAL {0}' ile dışa açın", "if it is used from other files, export it with 'GLOBAL {0}'"),
+    ("label.string-end", "satır burada bitiyor", "the line ends here"),
+    ("help.escaped-quote", "'\\\"' kaçışla yazılmış bir tırnaktır; ters bölü metnin parçasıysa '\\\\' olarak yazın", "'\\\"' is an escaped quote; if the backslash is part of the text, write it as '\\\\'"),
+    ("label.first-definition", "ilk tanım burada", "first defined here"),
+    ("label.shadowed-definition", "gölgelenen tanım burada", "shadowed definition is here"),
+    ("label.confusable-definition", "benzer görünen tanım burada", "similar-looking definition is here"),
+    ("label.flow-ends", "akış burada koşulsuz olarak sona eriyor", "control flow unconditionally ends here"),
+    ("note.lint", "'{0}' uyarısı (kapatmak için: -A {0})", "'{0}' lint (disable with: -A {0})"),
+    ("note.lint-denied", "'{0}' kategorisi hata olarak işaretlendi (-D {0})", "'{0}' is treated as an error (-D {0})"),
+    ("note.warnings-as-errors", "-Werror nedeniyle '{0}' uyarısı hataya dönüştürüldü", "'{0}' warning turned into an error by -Werror"),
+    ("token.end-of-file", "dosya sonu", "end of file"),
+    ("token.newline", "satır sonu", "end of line"),
+    ("token.statement", "deyim", "a statement"),
+    ("token.macro-parameter", "makro parametresi", "a macro parameter"),
+    // İşlenen yerlerinin ve biçimlerinin adları (B0306.instruction)
+    ("operand.register", "bir register", "a register"),
+    ("operand.destination", "bir register, değişken veya bellek başvurusu", "a register, variable or memory reference"),
+    ("operand.source", "bir register, sabit, değişken, adres veya bellek başvurusu", "a register, immediate, variable, address or memory reference"),
+    ("operand.output", "bir değer, değişken veya string", "a value, variable or string"),
+    ("operand.label", "bir etiket adı", "a label name"),
+    ("operand.procedure", "bir prosedür adı", "a procedure name"),
+    ("operand-kind.register", "register", "a register"),
+    ("operand-kind.immediate", "sabit değer", "an immediate"),
+    ("operand-kind.string", "string", "a string"),
+    ("operand-kind.address", "adres", "an address"),
+    ("operand-kind.memory", "bellek başvurusu", "a memory reference"),
+    ("operand-kind.name", "ad", "a name"),
+
+    // Tanılama gösterimi
+    ("ui.error", "hata", "error"),
+    ("ui.warning", "uyarı", "warning"),
+    ("ui.note", "not", "note"),
+    ("ui.help", "yardım", "help"),
+    ("ui.error-summary", "Hata", "Error"),
+    ("ui.warning-summary", "Uyarı", "Warning"),
+    ("ui.summary", "{0} (Satır: {1}, Sütun: {2}): {3}", "{0} (line {1}, column {2}): {3}"),
+    ("ui.summary-without-location", "{0} (Bilinmeyen konum): {1}", "{0} (unknown location): {1}"),
+    ("ui.explained-codes", "Ayrıntılı açıklaması olan hatalar: {0}.", "Some errors have detailed explanations: {0}."),
+    (
+        "ui.explain-any",
+        "Bir hata hakkında daha fazla bilgi için 'bessc --explain {0}' komutunu kullanın.",
+        "For more information about an error, try 'bessc --explain {0}'.",
+    ),
+    (
+        "ui.explain-one",
+        "Bu hata hakkında daha fazla bilgi için 'bessc --explain {0}' komutunu kullanın.",
+        "For more information about this error, try 'bessc --explain {0}'.",
+    ),
+    ("ui.failing-example", "Hatalı örnek:", "Erroneous example:"),
+    ("ui.fixed-example", "Düzeltilmiş örnek:", "Fixed example:"),
+
+    // Komut satırı aracı
+    ("emit.unavailable", "kullanılamıyor (önceki bir aşama başarısız oldu)", "unavailable (an earlier stage failed)"),
+    ("driver.build-succeeded", "Derleme başarılı. Çıktı dosyası: {0}", "Build succeeded. Output file: {0}"),
+    ("driver.no-command", "komut belirtilmedi", "no command given"),
+    ("driver.no-input", "girdi dosyası belirtilmedi", "no input files given"),
+    ("driver.unknown-command", "bilinmeyen komut '{0}'", "unknown command '{0}'"),
+    ("driver.unknown-option", "bilinmeyen seçenek '{0}'", "unknown option '{0}'"),
+    ("driver.unknown-value", "bilinmeyen {0} değeri '{1}'", "unknown {0} value '{1}'"),
+    ("driver.missing-file", "{0} seçeneği bir dosya adı bekliyor", "{0} expects a file name"),
+    ("driver.missing-emit-kind", "{0} seçeneği en az bir tür bekliyor", "{0} expects at least one kind"),
+    ("driver.missing-value", "{0} seçeneği {1} veya {2} bekliyor", "{0} expects {1} or {2}"),
+    ("driver.missing-lint", "{0} seçeneği bir uyarı kategorisi bekliyor", "{0} expects a lint name"),
+    ("driver.unknown-lint", "bilinmeyen uyarı kategorisi '{0}'", "unknown lint '{0}'"),
+    ("driver.missing-code", "{0} seçeneği bir tanılama kodu bekliyor", "{0} expects a diagnostic code"),
+    ("driver.unknown-code", "bilinmeyen tanılama kodu '{0}'", "unknown diagnostic code '{0}'"),
+    ("driver.runner-not-set", "'run' için BESSC_RUNNER ortam değişkeni ayarlanmalı", "'run' requires the BESSC_RUNNER environment variable"),
+    ("driver.file-not-found", "'{0}' bulunamadı", "'{0}' not found"),
+    ("driver.runner-failed", "'{0}' çalıştırılamadı: {1}", "could not run '{0}': {1}"),
+    (
+        "driver.usage",
+        "\
+Kullanım: bessc <komut> [seçenekler] <girdi.b64>...
+       bessc --explain <KOD>
+
+Komutlar:
+    build    Kaynakları ayrı ayrı derleyip tek bir .bs64 çalıştırılabilir dosyasında bağlar
+    check    Yalnızca ayrıştırma ve semantik analiz yapar, çıktı üretmez
+    run      Derler ve çıktıyı BESSC_RUNNER ile çalıştırır
+    emit     Ara çıktıları (varsayılan: bağlanmış kod) standart çıktıya (veya -o ile dosyaya) yazar
+
+Seçenekler:
+    -o <dosya>       Çıktı dosyası (varsayılan: ilk girdinin adı, .bs64 uzantılı)
+    --emit <türler>  Virgülle ayrılmış ara çıktılar: tokens, ast, symbols, memory, code, link
+    --emit-format <text|json>
+                     Ara çıktı biçimi (varsayılan: text)
+    --message-format <human|json>
+                     Tanılama biçimi; json her tanılamayı standart hataya
+                     tek satırlık bir JSON nesnesi olarak yazar (varsayılan: human)
+    --lang <tr|en>   Mesaj dili (varsayılan: LC_ALL, LC_MESSAGES veya LANG; yoksa tr)
+    --case-sensitive Anahtar kelime, komut, flag ve register adlarını yalnızca
+                     büyük harfle tanır (varsayılan: \"jump\" ve \"JUMP\" aynıdır)
+    -A <uyarı>       Uyarı kategorisini kapatır
+    -W <uyarı>       Uyarı kategorisini uyarı olarak bildirir (varsayılan)
+    -D <uyarı>       Uyarı kategorisini hataya dönüştürür
+    -Werror          Tüm uyarıları hataya dönüştürür
+    --explain <KOD>  Bir tanılama kodunun (ör. B0202) ayrıntılı açıklamasını gösterir
+    -h, --help       Bu yardım metnini gösterir
+    -V, --version    Sürüm bilgisini gösterir
+
+Uyarı kategorileri:
+    unused-label, shadowed-symbol, truncating-immediate, unreachable-code,
+    redundant-declaration, confusable-identifier",
+        "\
+Usage: bessc <command> [options] <input.b64>...
+       bessc --explain <CODE>
+
+Commands:
+    build    Compiles the sources separately and links them into a single .bs64 executable
+    check    Only parses and runs semantic analysis, produces no output
+    run      Compiles and runs the output with BESSC_RUNNER
+    emit     Writes intermediate outputs (default: linked code) to standard output (or to a file with -o)
+
+Options:
+    -o <file>        Output file (default: the first input's name with a .bs64 extension)
+    --emit <kinds>   Comma-separated intermediate outputs: tokens, ast, symbols, memory, code, link
+    --emit-format <text|json>
+                     Format of intermediate outputs (default: text)
+    --message-format <human|json>
+                     Diagnostic format; json writes each diagnostic to standard
+                     error as a single-line JSON object (default: human)
+    --lang <tr|en>   Message language (default: LC_ALL, LC_MESSAGES or LANG; otherwise tr)
+    --case-sensitive Recognizes keywords, instructions, flags and register names
+                     only in upper case (default: \"jump\" and \"JUMP\" are the same)
+    -A <lint>        Disables a lint
+    -W <lint>        Reports a lint as a warning (default)
+    -D <lint>        Turns a lint into an error
+    -Werror          Turns all warnings into errors
+    --explain <CODE> Shows a detailed explanation of a diagnostic code (e.g. B0202)
+    -h, --help       Shows this help text
+    -V, --version    Shows version information
+
+Lints:
+    unused-label, shadowed-symbol, truncating-immediate, unreachable-code,
+    redundant-declaration, confusable-identifier",
+    ),
+
+    // `bessc --explain` başlıkları ve açıklamaları
+    ("B0101.title", "Beklenmeyen karakter", "Unexpected character"),
+    (
+        "B0101.description",
+        "Kaynakta dilin hiçbir token'ına karşılık gelmeyen bir karakter var. Genellikle\n\
+         yanlışlıkla yazılmış bir noktalama işareti ya da başka bir dilden kopyalanmış\n\
+         bir satırdır.",
+        "The source contains a character that does not start any token of the language.\n\
+         Usually it is a stray punctuation mark or a line copied from another language.",
+    ),
+    ("B0102.title", "Kapatılmamış string sabiti", "Unterminated string literal"),
+    (
+        "B0102.description",
+        "String sabitleri aynı satırda '\"' ile kapatılmalıdır; satır sonu string'i\n\
+         sonlandırmaz. Derleyici string'i satır sonunda bitmiş sayıp devam eder.",
+        "String literals must be closed with '\"' on the same line; the end of the line\n\
+         does not terminate a string. The compiler treats the string as ending at the\n\
+         end of the line and continues.",
+    ),
+    ("B0103.title", "Beklenmeyen token", "Unexpected token"),
+    (
+        "B0103.description",
+        "Komutun sözdiziminde belirli bir anahtar kelime veya işaret bekleniyordu ama\n\
+         başka bir token bulundu. Mesaj, beklenen ve bulunan token'ı gösterir.",
+        "The syntax of the instruction requires a specific keyword or punctuation mark,\n\
+         but a different token was found. The message shows the expected and the found\n\
+         token.",
+    ),
+    ("B0104.title", "İfade bekleniyor", "Expected expression"),
+    (
+        "B0104.description",
+        "Komut bir değer (sayı, tanımlayıcı, flag veya string) bekliyordu ama bir\n\
+         işaret ya da satır sonu bulundu.",
+        "The instruction expects a value (a number, identifier, flag or string) but\n\
+         found punctuation or the end of the line.",
+    ),
+    ("B0105.title", "Komutun işleneni eksik veya hatalı", "Missing or invalid instruction operand"),
+    (
+        "B0105.description",
+        "JUMP, FLAG, SPAWN, ALLOCATE ... AS, GLOBAL ve EXTERN bir ad bekler. Ad yerine\n\
+         sayı veya başka bir token yazılmış ya da ad hiç yazılmamış.",
+        "JUMP, FLAG, SPAWN, ALLOCATE ... AS, GLOBAL and EXTERN expect a name. A number\n\
+         or another token was written instead, or the name is missing.",
+    ),
+    ("B0106.title", "Bilinmeyen direktif", "Unknown directive"),
+    (
+        "B0106.description",
+        "Nokta ile başlayan ad bilinen bir direktif değil. Geçerli direktifler: .data ve\n\
+         .code (bölüm seçimi), .global ve .extern (dosyalar arası semboller), .string,\n\
+         .byte, .word, .dword ve .qword (veri).",
+        "The name after the dot is not a known directive. Valid directives are .data and\n\
+         .code (section selection), .global and .extern (symbols shared between files),\n\
+         .string, .byte, .word, .dword and .qword (data).",
+    ),
+    ("B0107.title", "Geçersiz sayı sabiti", "Invalid number literal"),
+    (
+        "B0107.description",
+        "Sayı sabitleri ondalık (42), onaltılık (0xFF), ikilik (0b1010) veya sekizlik\n\
+         (0o755) yazılabilir; basamaklar '_' ile ayrılabilir (0xFFFF_0000). Basamak,\n\
+         ön ekin tabanında geçerli değil ya da ön ekten sonra hiç basamak yok. Karakter\n\
+         sabitleri ('A') tek tırnak içinde tam olarak bir karakter içermelidir.",
+        "Number literals may be written in decimal (42), hexadecimal (0xFF), binary\n\
+         (0b1010) or octal (0o755); digits may be separated with '_' (0xFFFF_0000). A\n\
+         digit is not valid in the base of the prefix, or no digits follow the prefix.\n\
+         Character literals ('A') must contain exactly one character in single quotes.",
+    ),
+    ("B0108.title", "Sayı sabiti 64 bite sığmıyor", "Number literal does not fit in 64 bits"),
+    (
+        "B0108.description",
+        "Sayı sabitleri 64 bitlik değerlerdir. İşaretsiz sabitler en fazla\n\
+         0xFFFF_FFFF_FFFF_FFFF, negatif sabitler en az -9223372036854775808 olabilir.",
+        "Number literals are 64-bit values. Unsigned literals may be at most\n\
+         0xFFFF_FFFF_FFFF_FFFF and negative literals at least -9223372036854775808.",
+    ),
+    ("B0109.title", "Geçersiz kaçış dizisi", "Invalid escape sequence"),
+    (
+        "B0109.description",
+        "String ve karakter sabitlerinde ters bölü bir kaçış dizisi başlatır. Geçerli\n\
+         diziler: \\n, \\t, \\r, \\0, \\\\, \\\", \\', ASCII karakterler için \\x00-\\x7F ve\n\
+         herhangi bir Unicode karakteri için \\u{...}. Ters bölünün kendisi \\\\ ile yazılır.",
+        "In string and character literals a backslash starts an escape sequence. Valid\n\
+         sequences are \\n, \\t, \\r, \\0, \\\\, \\\", \\', \\x00-\\x7F for ASCII characters and\n\
+         \\u{...} for any Unicode character. A backslash itself is written as \\\\.",
+    ),
+    ("B0110.title", "Satır sonu bekleniyor", "Expected end of line"),
+    (
+        "B0110.description",
+        "Her satırda en fazla bir deyim bulunabilir; yalnızca etiketlerin ardından aynı\n\
+         satırda bir deyim gelebilir (\"LOOP: RELEASE MEM1\"). Deyim bittikten sonra\n\
+         satırda yorum dışında bir şey kalmış.",
+        "Each line may hold at most one statement; only a label may be followed by a\n\
+         statement on the same line (\"LOOP: RELEASE MEM1\"). Something other than a\n\
+         comment is left on the line after the statement ended.",
+    ),
+    ("B0201.title", "Tanımsız değişken", "Undefined variable"),
+    (
+        "B0201.description",
+        "Bir değişken, bu kapsamda VAR ile tanımlanmadan kullanılmış. Değişkenler\n\
+         kullanılmadan önce tanımlanmalıdır.",
+        "A variable is used without being declared with VAR in this scope. Variables\n\
+         must be declared before they are used.",
+    ),
+    ("B0202.title", "Tanımsız etiket", "Undefined label"),
+    (
+        "B0202.description",
+        "JUMP veya koşullu atlama (JEQ, JLT ...) hedefi bu dosyada tanımlı değil ve\n\
+         EXTERN ile bildirilmemiş. Etiket başka bir dosyada tanımlıysa o dosyada GLOBAL,\n\
+         bu dosyada EXTERN olarak bildirin.",
+        "The target of JUMP or a conditional jump (JEQ, JLT ...) is not defined in this\n\
+         file and is not declared with EXTERN. If the label is defined in another file,\n\
+         declare it GLOBAL there and EXTERN here.",
+    ),
+    ("B0203.title", "Tanımsız prosedür", "Undefined procedure"),
+    (
+        "B0203.description",
+        "SPAWN veya CALL ile kullanılan prosedür tanımlı değil. Prosedürler\n\
+         PROCEDURE_<AD>: etiketiyle tanımlanır ya da EXTERN ile bildirilir.",
+        "The procedure used with SPAWN or CALL is not defined. Procedures are defined\n\
+         with a PROCEDURE_<NAME>: label or declared with EXTERN.",
+    ),
+    ("B0204.title", "Sembol zaten tanımlı", "Symbol is already defined"),
+    (
+        "B0204.description",
+        "Aynı ad aynı kapsamda ikinci kez tanımlanmış. Etiketler, değişkenler, handle'lar\n\
+         ve prosedürler kapsamları içinde tekil olmalıdır; ilk tanımın yeri tanılamada\n\
+         ayrıca işaretlenir.",
+        "The same name is defined twice in the same scope. Labels, variables, handles\n\
+         and procedures must be unique within their scope; the location of the first\n\
+         definition is marked in the diagnostic.",
+    ),
+    ("B0205.title", "Sembol hem GLOBAL hem EXTERN", "Symbol is both GLOBAL and EXTERN"),
+    (
+        "B0205.description",
+        "Bir sembol ya bu dosyada tanımlanıp dışa açılır (GLOBAL) ya da başka bir\n\
+         dosyadan beklenir (EXTERN); ikisi birden olamaz.",
+        "A symbol is either defined and exported by this file (GLOBAL) or expected from\n\
+         another file (EXTERN); it cannot be both.",
+    ),
+    ("B0206.title", "Bilinmeyen tip", "Unknown type"),
+    (
+        "B0206.description",
+        "VAR ile verilen tip adı tanınmıyor. Geçerli tipler: BYTE, WORD, DWORD, QWORD,\n\
+         USIZE, PTR, STRING, HANDLE ve TASK_ID.",
+        "The type name given to VAR is not recognized. Valid types are BYTE, WORD,\n\
+         DWORD, QWORD, USIZE, PTR, STRING, HANDLE and TASK_ID.",
+    ),
+    ("B0301.title", "Tip uyuşmazlığı", "Mismatched types"),
+    (
+        "B0301.description",
+        "Bir değerin tipi, kullanıldığı yerin beklediği tiple uyuşmuyor. Örneğin\n\
+         RELEASE bir handle bekler, string sabiti alamaz.",
+        "The type of a value does not match the type expected where it is used. For\n\
+         example, RELEASE expects a handle and cannot take a string literal.",
+    ),
+    ("B0302.title", "Sayısal olmayan işlenen", "Non-numeric operand"),
+    (
+        "B0302.description",
+        "Aritmetik komutlar (ADD, SUB, MUL, DIV) yalnızca sayıları ve sayısal tipteki\n\
+         değişkenleri kabul eder.",
+        "Arithmetic instructions (ADD, SUB, MUL, DIV) only accept numbers and variables\n\
+         of numeric types.",
+    ),
+    ("B0303.title", "Karşılaştırılamayan işlenen", "Operand cannot be compared"),
+    (
+        "B0303.description",
+        "Karşılaştırma komutları sayı, register, değişken veya flag ile çalışır; STRING\n\
+         tipindeki değişkenler karşılaştırılamaz.",
+        "Comparison instructions work with numbers, registers, variables or flags;\n\
+         variables of type STRING cannot be compared.",
+    ),
+    ("B0304.title", "Mantıksal olmayan işlenen", "Non-logical operand"),
+    (
+        "B0304.description",
+        "Mantıksal komutlar (AND, OR, XOR, NOT) tamsayı değerler, tamsayı tipindeki\n\
+         değişkenler veya flag'ler üzerinde çalışır.",
+        "Logical instructions (AND, OR, XOR, NOT) work on integer values, variables of\n\
+         integer types or flags.",
+    ),
+    ("B0305.title", "Yanlış sayıda işlenen", "Wrong number of operands"),
+    (
+        "B0305.description",
+        "Komut, aldığı işlenen sayısıyla kullanılamaz. Örneğin VAR bir ad ve bir tip,\n\
+         aritmetik komutlar virgülle ayrılmış iki işlenen bekler.",
+        "The instruction cannot be used with the given number of operands. For example,\n\
+         VAR expects a name and a type, arithmetic instructions expect two\n\
+         comma-separated operands.",
+    ),
+    ("B0306.title", "Geçersiz işlenen türü", "Invalid operand kind"),
+    (
+        "B0306.description",
+        "İşlenen sayısı doğru ama türü yanlış: örneğin READ bir register, değişken veya\n\
+         bellek başvurusu, CALL bir prosedür adı bekler.",
+        "The number of operands is right but an operand has the wrong kind: for\n\
+         example, READ expects a register, variable or memory reference and CALL\n\
+         expects a procedure name.",
+    ),
+    ("B0307.title", "Hatalı Sahne64 API çağrısı", "Invalid Sahne64 API call"),
+    (
+        "B0307.description",
+        "Bir Sahne64 API komutu, API'nin kabul etmediği bir biçimde kullanılmış.\n\
+         Mesaj, ilgili API çağrısını ve sorunu gösterir.",
+        "A Sahne64 API instruction is used in a form the API does not accept. The\n\
+         message names the API call and the problem.",
+    ),
+    ("B0308.title", "Geçersiz register", "Invalid register"),
+    (
+        "B0308.description",
+        "Register numarası hedef makinenin register sayısını aşıyor. Sahne64 hedefinde\n\
+         R0'dan R7'ye kadar sekiz genel amaçlı register vardır.",
+        "The register number is beyond the register count of the target machine. The\n\
+         Sahne64 target has eight general-purpose registers, R0 through R7.",
+    ),
+    ("B0401.title", "Statik değişken zaten yerleştirilmiş", "Static variable is already allocated"),
+    (
+        "B0401.description",
+        "Bellek yerleşimi sırasında aynı ada ikinci bir statik alan ayrılmak istendi.\n\
+         Statik değişkenler dosya başına tek bir ad alanında yerleşir; bir prosedürdeki\n\
+         VAR, dış kapsamdaki aynı adlı değişkenle aynı adresi paylaşamaz.",
+        "During memory layout, a second static area was requested for the same name.\n\
+         Static variables share one namespace per file; a VAR inside a procedure cannot\n\
+         share its address with a variable of the same name in an outer scope.",
+    ),
+    ("B0402.title", "Handle zaten tanımlı", "Handle is already defined"),
+    (
+        "B0402.description",
+        "Aynı handle adı birden fazla ALLOCATE, ACQUIRE veya GET_* komutunun hedefi\n\
+         olmuş. Prosedür içindeki tanımlar da aynı dosya genelindeki handle tablosuna\n\
+         yerleşir.",
+        "The same handle name is the target of more than one ALLOCATE, ACQUIRE or GET_*\n\
+         instruction. Definitions inside procedures are placed in the same file-wide\n\
+         handle table.",
+    ),
+    ("B0403.title", "Tanımsız handle", "Undefined handle"),
+    (
+        "B0403.description",
+        "RELEASE, CTRL, SEND veya RECV komutunun kullandığı handle daha önce ALLOCATE,\n\
+         ACQUIRE veya GET_* ile edinilmemiş ve bir değişkende de tutulmuyor.",
+        "The handle used by RELEASE, CTRL, SEND or RECV was not obtained earlier with\n\
+         ALLOCATE, ACQUIRE or GET_* and is not held in a variable either.",
+    ),
+    ("B0501.title", "GLOBAL sembol bu dosyada tanımlı değil", "GLOBAL symbol is not defined in this file"),
+    (
+        "B0501.description",
+        "GLOBAL ile dışa açılan bir sembol, onu açan dosyada tanımlanmalıdır.",
+        "A symbol exported with GLOBAL must be defined in the file that exports it.",
+    ),
+    ("B0502.title", "GLOBAL sembol birden fazla dosyada tanımlı", "GLOBAL symbol is defined in more than one file"),
+    (
+        "B0502.description",
+        "Bağlanan dosyalardan ikisi aynı sembolü GLOBAL olarak dışa açıyor. Dışa açılan\n\
+         her sembolün tek bir sahibi olmalıdır; diğer dosyalar onu EXTERN ile kullanır.",
+        "Two of the linked files export the same symbol as GLOBAL. Every exported\n\
+         symbol must have a single owner; other files use it through EXTERN.",
+    ),
+    ("B0503.title", "Çözümlenemeyen harici sembol", "Unresolved external symbol"),
+    (
+        "B0503.description",
+        "EXTERN ile beklenen sembolü bağlanan dosyaların hiçbiri GLOBAL olarak dışa\n\
+         açmıyor. Sembolü tanımlayan dosyayı derlemeye ekleyin ya da orada GLOBAL\n\
+         olarak bildirin.",
+        "None of the linked files exports the symbol expected with EXTERN as GLOBAL.\n\
+         Add the file that defines the symbol to the build or declare it GLOBAL there.",
+    ),
+    ("B0601.title", "Kullanılmayan etiket (uyarı: unused-label)", "Unused label (lint: unused-label)"),
+    (
+        "B0601.description",
+        "Etikete hiçbir JUMP, SPAWN veya komut işleneni başvurmuyor. PROCEDURE_ ile\n\
+         başlayan etiketler bu uyarının dışındadır. '-A unused-label' ile kapatılabilir.",
+        "No JUMP, SPAWN or instruction operand refers to the label. Labels starting with\n\
+         PROCEDURE_ are exempt. Disable with '-A unused-label'.",
+    ),
+    ("B0602.title", "Gölgelenen sembol (uyarı: shadowed-symbol)", "Shadowed symbol (lint: shadowed-symbol)"),
+    (
+        "B0602.description",
+        "Prosedür içindeki bir tanım, dış kapsamdaki aynı adlı sembolü gizliyor; bu\n\
+         addaki başvurular artık iç tanımı görür. '-A shadowed-symbol' ile kapatılabilir.",
+        "A definition inside a procedure hides a symbol with the same name in an outer\n\
+         scope; references to the name now see the inner definition. Disable with\n\
+         '-A shadowed-symbol'.",
+    ),
+    ("B0603.title", "Tipe sığmayan sabit (uyarı: truncating-immediate)", "Constant does not fit its type (lint: truncating-immediate)"),
+    (
+        "B0603.description",
+        "Sayısal sabit, atandığı tipin ya da veri direktifinin (.byte, .word, .dword)\n\
+         bit genişliğine sığmıyor ve kesilecek. '-A truncating-immediate' ile\n\
+         kapatılabilir.",
+        "The numeric constant does not fit in the bit width of its target type or\n\
+         data directive (.byte, .word, .dword) and will be truncated. Disable with\n\
+         '-A truncating-immediate'.",
+    ),
+    ("B0604.title", "Ulaşılamaz kod (uyarı: unreachable-code)", "Unreachable code (lint: unreachable-code)"),
+    (
+        "B0604.description",
+        "Koşulsuz JUMP veya EXIT'ten sonra gelen kod, önüne bir etiket konmadıkça hiç\n\
+         çalışmaz. '-A unreachable-code' ile kapatılabilir.",
+        "Code following an unconditional JUMP or EXIT never runs unless a label is\n\
+         placed before it. Disable with '-A unreachable-code'.",
+    ),
+    ("B0605.title", "Tekrarlanan bildirim (uyarı: redundant-declaration)", "Redundant declaration (lint: redundant-declaration)"),
+    (
+        "B0605.description",
+        "Aynı sembol aynı GLOBAL veya EXTERN direktifiyle birden fazla kez bildirilmiş;\n\
+         fazladan bildirimlerin etkisi yoktur. '-A redundant-declaration' ile kapatılabilir.",
+        "The same symbol is declared more than once with the same GLOBAL or EXTERN\n\
+         directive; the extra declarations have no effect. Disable with\n\
+         '-A redundant-declaration'.",
+    ),
+    ("B0606.title", "Karıştırılabilir ad (uyarı: confusable-identifier)", "Confusable identifier (lint: confusable-identifier)"),
+    (
+        "B0606.description",
+        "Yeni tanımlanan ad, var olan bir adla yalnızca ekranda aynı görünen harflerde\n\
+         ayrılıyor (ör. noktalı ve noktasız i ya da Latin ve Kiril 'a'). Adlar NFC\n\
+         biçiminde karşılaştırıldığından bunlar iki ayrı semboldür ve okurken\n\
+         karıştırılabilir. '-A confusable-identifier' ile kapatılabilir.",
+        "The new name differs from an existing one only in letters that look the same\n\
+         on screen (e.g. dotted and dotless i, or Latin and Cyrillic 'a'). Names are\n\
+         compared in NFC form, so these are two distinct symbols that are easy to mix\n\
+         up when reading. Disable with '-A confusable-identifier'.",
+    ),
+    ("B0901.title", "İç derleyici hatası", "Internal compiler error"),
+    (
+        "B0901.description",
+        "Derleyici kendi iç tutarlılık denetimlerinden birinde başarısız oldu. Bu bir\n\
+         kaynak hatası değil, derleyicideki bir hatadır; lütfen hatayı üreten kaynakla\n\
+         birlikte bildirin.",
+        "The compiler failed one of its own consistency checks. This is not an error in\n\
+         the source but a bug in the compiler; please report it together with the\n\
+         source that triggers it.",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn placeholders(template: &str) -> Vec<usize> {
+        let mut found: Vec<usize> = (0..10).filter(|index| template.contains(&format!("{{{}}}", index))).collect();
+        found.sort();
+        found
+    }
+
+    #[test]
+    fn test_every_code_has_both_translations() {
+        let keys: HashSet<&str> = CATALOG.iter().map(|(key, _, _)| *key).collect();
+        assert_eq!(keys.len(), CATALOG.len(), "katalogda tekrarlanan anahtar var");
+
+        for (key, turkish, english) in CATALOG {
+            assert!(!turkish.is_empty() && !english.is_empty(), "'{}' için çeviri eksik", key);
+            assert_eq!(placeholders(turkish), placeholders(english), "'{}' çevirilerinin yer tutucuları farklı", key);
+        }
+        for code in DiagnosticCode::ALL {
+            let code = code.as_str();
+            let has_message = keys.iter().any(|key| {
+                *key == code || (key.starts_with(&format!("{}.", code)) && !key.ends_with(".title") && !key.ends_with(".description"))
+            });
+            assert!(has_message, "{} için mesaj yok", code);
+            assert!(keys.contains(format!("{}.title", code).as_str()), "{} için başlık yok", code);
+            assert!(keys.contains(format!("{}.description", code).as_str()), "{} için açıklama yok", code);
+        }
+    }
+
+    // Kaynak kodda msg!, Arg::Key, messages::lookup ve key() tablolarıyla yazılan anahtarlar
+    fn keys_used_in_sources() -> Vec<&'static str> {
+        let sources = [
+        include_str!("codegen.rs"),
+        include_str!("compiler.rs"),
+        include_str!("control_flow.rs"),
+        include_str!("diagnostic_code.rs"),
+        include_str!("emit.rs"),
+        include_str!("error_reporter.rs"),
+        include_str!("extern_symbols.rs"),
+        include_str!("file_provider.rs"),
+        include_str!("function_handle.rs"),
+        include_str!("instructions.rs"),
+        include_str!("io_handler.rs"),
+        include_str!("json.rs"),
+        include_str!("keywords.rs"),
+        include_str!("lexer.rs"),
+        include_str!("lib.rs"),
+        include_str!("linker.rs"),
+        include_str!("macros.rs"),
+        include_str!("main.rs"),
+        include_str!("memory_manager.rs"),
+        include_str!("messages.rs"),
+        include_str!("operator_handler.rs"),
+        include_str!("parser.rs"),
+        include_str!("semantic_analyzer.rs"),
+        include_str!("symbol_table.rs"),
+        include_str!("target.rs"),
+        include_str!("types.rs"),
+        include_str!("unicode.rs"),
+        include_str!("variable_handler.rs"),
+        ];
+        // " => \"..." yalnızca OperandKind/OperandSlot::key() tablolarındaki "operand" anahtarları için
+        let prefixes = ["msg!(\"", "Key(\"", "lookup(language, \"", " => \""];
+        let mut keys = Vec::new();
+        for source in sources {
+            for prefix in prefixes {
+                for (start, _) in source.match_indices(prefix) {
+                    let rest = &source[start + prefix.len()..];
+                    let key = &rest[..rest.find('"').unwrap_or(0)];
+                    if prefix != " => \"" || key.starts_with("operand") {
+                        keys.push(key);
+                    }
+                }
+            }
+        }
+        keys
+    }
+
+    #[test]
+    fn test_every_key_used_in_the_sources_is_in_the_catalog() {
+        let used = keys_used_in_sources();
+        assert!(used.len() > 100, "kaynaklarda anahtar bulunamadı");
+        for key in used {
+            assert!(CATALOG.iter().any(|(k, _, _)| *k == key), "'{}' anahtarı katalogda yok", key);
+        }
+    }
+
+    #[test]
+    fn test_message_text_and_language_selection() {
+        let message = msg!("B0103", "'AS'", Arg::Key("token.end-of-file"));
+        assert_eq!(message.text(Language::Turkish), "'AS' bekleniyor, dosya sonu bulundu");
+        assert_eq!(message.text(Language::English), "expected 'AS', found end of file");
+        assert_eq!(Language::from_name("en_US.UTF-8"), Some(Language::English));
+        assert_eq!(Language::from_name("tr"), Some(Language::Turkish));
+        assert_eq!(Language::from_name("C"), None);
+    }
+}
@@ -1,159 +1,1054 @@
-use crate::lexer::{Lexer, Token, TokenType};
-
-#[derive(Debug)]
-pub enum AstNode {
-    JumpStatement { target: String },
-    FlagDeclaration { flag: String },
-    Assignment { variable: String, value: Expression },
-    AllocateMemory { size: Expression, handle: String },
-    ReleaseMemory { handle: Expression },
-    SpawnTask { function: String, priority: Option<Expression> },
-    ExitTask { code: Option<Expression> },
-    SleepTask { duration: Expression },
-    YieldTask,
-    AcquireResource { name: Expression, handle: String },
-    ControlResource { handle: Expression, command: Expression },
-    SendMessage { handle: Expression, message: Expression },
-    ReceiveMessage { handle: Expression, buffer: String }, // Basit buffer adı
-    GetTaskId { target: String },
-    GetCoreId { target: String },
-    GetTotalCores { target: String },
-    Instruction { opcode: String, operands: Vec<Expression> },
-    Label { name: String },
-    Program(Vec<AstNode>),
-}
-
-#[derive(Debug)]
-pub enum Expression {
-    Identifier(String),
-    Number(i64),
-    Flag(String),
-    StringLiteral(String),
-    Handle(usize),
-    TaskId(usize),
-}
-
-pub struct Parser {
-    lexer: Lexer,
-    current_token: Token,
-}
-
-impl Parser {
-    pub fn new(input: String) -> Self {
-        let mut lexer = Lexer::new(input);
-        let first_token = lexer.next_token();
-        Parser {
-            lexer,
-            current_token: first_token,
-        }
-    }
-
-    fn advance(&mut self) {
-        self.current_token = self.lexer.next_token();
-    }
-
-    fn consume(&mut self, expected_type: TokenType) {
-        if self.current_token.token_type == expected_type {
-            self.advance();
-        } else {
-            panic!(
-                "Beklenen token: {:?}, bulunan: {:?} (satır {}, sütun {})",
-                expected_type,
-                self.current_token.token_type,
-                self.current_token.line,
-                self.current_token.column
-            );
-        }
-    }
-
-    fn parse_expression(&mut self) -> Expression {
-        match &self.current_token.token_type {
-            TokenType::Identifier(name) => {
-                let expr = Expression::Identifier(name.clone());
-                self.advance();
-                expr
-            }
-            TokenType::Number(value) => {
-                let expr = Expression::Number(*value);
-                self.advance();
-                expr
-            }
-            TokenType::Flag(flag) => {
-                let expr = Expression::Flag(flag.clone());
-                self.advance();
-                expr
-            }
-            TokenType::StringLiteral(s) => {
-                let expr = Expression::StringLiteral(s.clone());
-                self.advance();
-                expr
-            }
-            _ => panic!("Beklenen ifade, bulunan: {:?} (satır {}, sütun {})", self.current_token.token_type, self.current_token.line, self.current_token.column),
-        }
-    }
-
-    fn parse_optional_expression(&mut self) -> Option<Expression> {
-        match &self.current_token.token_type {
-            TokenType::Identifier(_) | TokenType::Number(_) | TokenType::Flag(_) | TokenType::StringLiteral(_) => {
-                Some(self.parse_expression())
-            }
-            _ => None,
-        }
-    }
-
-    fn parse_statement(&mut self) -> Option<AstNode> {
-        match &self.current_token.token_type {
-            TokenType::Keyword(keyword) => match keyword.as_str() {
-                "JUMP" => {
-                    self.advance();
-                    match &self.current_token.token_type {
-                        TokenType::Identifier(target) => {
-                            let node = AstNode::JumpStatement { target: target.clone() };
-                            self.advance();
-                            Some(node)
-                        }
-                        _ => panic!("JUMP komutundan sonra hedef bekleniyor (satır {}, sütun {})", self.current_token.line, self.current_token.column),
-                    }
-                }
-                "FLAG" => {
-                    self.advance();
-                    match &self.current_token.token_type {
-                        TokenType::Flag(flag) => {
-                            let node = AstNode::FlagDeclaration { flag: flag.clone() };
-                            self.advance();
-                            Some(node)
-                        }
-                        _ => panic!("FLAG komutundan sonra flag bekleniyor (satır {}, sütun {})", self.current_token.line, self.current_token.column),
-                    }
-                }
-                "ALLOCATE" => {
-                    self.advance();
-                    let size = self.parse_expression();
-                    self.consume(TokenType::Keyword("AS".to_string()));
-                    match &self.current_token.token_type {
-                        TokenType::Identifier(handle) => {
-                            let node = AstNode::AllocateMemory { size, handle: handle.clone() };
-                            self.advance();
-                            Some(node)
-                        }
-                        _ => panic!("ALLOCATE komutundan sonra AS ve handle bekleniyor (satır {}, sütun {})", self.current_token.line, self.current_token.column),
-                    }
-                }
-                "RELEASE" => {
-                    self.advance();
-                    let handle = self.parse_expression();
-                    Some(AstNode::ReleaseMemory { handle })
-                }
-                "SPAWN" => {
-                    self.advance();
-                    match &self.current_token.token_type {
-                        TokenType::Identifier(function) => {
-                            self.advance();
-                            let mut priority
-                        }
-                    }
-                }
-            }
-        }
-    }
-}
\ No newline at end of file
+use crate::diagnostic_code::DiagnosticCode;
+use crate::error_reporter::{Diagnostic, ErrorReporter};
+use crate::instructions::{self, OperandKind};
+use crate::lexer::{EscapeErrorKind, Lexer, NumberError, SourceLocation, Span, Token, TokenType};
+use std::collections::VecDeque;
+use crate::messages::{msg, Arg, Message};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AstNode {
+    JumpStatement { target: String },
+    FlagDeclaration { flag: String },
+    Assignment { variable: String, value: Expression },
+    AllocateMemory { size: Expression, handle: String },
+    ReleaseMemory { handle: Expression },
+    SpawnTask { function: String, priority: Option<Expression> },
+    ExitTask { code: Option<Expression> },
+    SleepTask { duration: Expression },
+    YieldTask,
+    AcquireResource { name: Expression, handle: String },
+    ControlResource { handle: Expression, command: Expression },
+    SendMessage { handle: Expression, message: Expression },
+    ReceiveMessage { handle: Expression, buffer: String }, // Basit buffer adı
+    GetTaskId { target: String },
+    GetCoreId { target: String },
+    GetTotalCores { target: String },
+    Instruction { opcode: String, operands: Vec<Expression> },
+    Label { name: String },
+    Section { section: Section },                          // .data / .code
+    DataDirective { kind: DataKind, values: Vec<Expression> }, // .string "..." / .byte 1, 2
+    Constant { name: String, value: Expression },          // Veri bölümünde "ad = ifade"
+    Program(Vec<Statement>),
+}
+
+// Sonraki deyimlerin yerleştirileceği bölüm; dosyalar kod bölümüyle başlar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Section {
+    Data,
+    #[default]
+    Code,
+}
+
+impl Section {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Section::Data => "data",
+            Section::Code => "code",
+        }
+    }
+}
+
+// Veri direktiflerinin ürettiği öğe türü
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataKind {
+    String,
+    Byte,
+    Word,
+    DWord,
+    QWord,
+}
+
+impl DataKind {
+    pub fn from_name(name: &str) -> Option<DataKind> {
+        match name {
+            "string" => Some(DataKind::String),
+            "byte" => Some(DataKind::Byte),
+            "word" => Some(DataKind::Word),
+            "dword" => Some(DataKind::DWord),
+            "qword" => Some(DataKind::QWord),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DataKind::String => "string",
+            DataKind::Byte => "byte",
+            DataKind::Word => "word",
+            DataKind::DWord => "dword",
+            DataKind::QWord => "qword",
+        }
+    }
+}
+
+// Programdaki bir deyim ve başladığı konum (tanılamalar için)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    pub node: AstNode,
+    pub location: SourceLocation,
+    pub operands: Vec<SourceLocation>, // Deyimdeki ifadelerin konumları, kaynaktaki sırasıyla
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    Identifier(String),
+    Number(i64),
+    Flag(String),
+    StringLiteral(String),
+    Handle(usize),
+    TaskId(usize),
+    Register(usize),
+    AddressOf(String), // $ad: etiketteki değer değil, etiketin adresi
+    LocationCounter, // '.': deyimin yerleştirildiği adres
+    Memory(Box<Expression>), // [ifade]: adresteki değer
+    Binary { operator: BinaryOperator, left: Box<Expression>, right: Box<Expression> },
+}
+
+// Sabit ifadelerde adres aritmetiği için ("hello_len = . - hello_msg")
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOperator {
+    Add,
+    Subtract,
+}
+
+impl BinaryOperator {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BinaryOperator::Add => "+",
+            BinaryOperator::Subtract => "-",
+        }
+    }
+}
+
+pub struct Parser {
+    tokens: Box<dyn Iterator<Item = Token>>,
+    current_token: Token,
+    lookahead: VecDeque<Token>,    // peek_nth ile okunmuş ama henüz tüketilmemiş token'lar
+    end_of_file: Token,            // Token dizisi bittiğinde verilecek, son token'ın ardındaki EndOfFile
+    previous_token: Option<Token>, // Son tüketilen token; deyimin kapsadığı aralık için
+    error_reporter: ErrorReporter, // Sözdizimi hataları; derleyici parse_program'dan sonra devralır
+    section: Section,              // Son .data/.code direktifi; "ad = ifade"nin anlamını belirler
+    operands: Vec<SourceLocation>, // Ayrıştırılan deyimin ifadelerinin konumları
+    expression_depth: usize,       // İç içe ifadeler ("[R1 + 4]") ayrı işlenen sayılmaz
+}
+
+impl Parser {
+    pub fn new(mut lexer: Lexer) -> Self {
+        lexer.set_keep_newlines(true); // Her satırda en fazla bir deyim (etiketler hariç)
+        Self::from_tokens(lexer)
+    }
+
+    pub fn from_source(source: &str) -> Self {
+        Self::new(Lexer::new(source.to_string()))
+    }
+
+    // Hazır bir token dizisinden (ör. makro genişletmesinden sonra). Dizinin
+    // EndOfFile ile bitmesi ya da Newline token'ları içermesi gerekmez; satırlar
+    // token'ların satır numaralarından ayırt edilir.
+    pub fn from_tokens<I>(tokens: I) -> Self
+    where
+        I: IntoIterator<Item = Token>,
+        I::IntoIter: 'static,
+    {
+        let start = Token { token_type: TokenType::EndOfFile, line: 1, column: 1, length: 0, span: Span::default() };
+        let mut parser = Parser {
+            tokens: Box::new(tokens.into_iter()),
+            current_token: start.clone(),
+            lookahead: VecDeque::new(),
+            end_of_file: start,
+            previous_token: None,
+            error_reporter: ErrorReporter::new(),
+            section: Section::default(),
+            operands: Vec::new(),
+            expression_depth: 0,
+        };
+        parser.current_token = parser.read_token();
+        parser
+    }
+
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        self.error_reporter.diagnostics()
+    }
+
+    pub fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        self.error_reporter.take_diagnostics()
+    }
+
+    // Geçerli token'ın konumunda bir sözdizimi hatası raporlar
+    fn error(&mut self, code: DiagnosticCode, message: Message) {
+        let location = self.current_token.location();
+        self.error_reporter.report_error_at(code, message, Some(location));
+    }
+
+    fn advance(&mut self) {
+        let next = match self.lookahead.pop_front() {
+            Some(token) => token,
+            None => self.read_token(),
+        };
+        self.previous_token = Some(std::mem::replace(&mut self.current_token, next));
+    }
+
+    fn read_token(&mut self) -> Token {
+        match self.tokens.next() {
+            Some(token) => {
+                self.end_of_file = Token {
+                    token_type: TokenType::EndOfFile,
+                    line: token.line,
+                    column: token.column + token.length,
+                    length: 0,
+                    span: Span { start: token.span.end, ..token.span },
+                };
+                token
+            }
+            None => self.end_of_file.clone(),
+        }
+    }
+
+    // start'tan son tüketilen token'ın sonuna kadar uzanan konum (aynı satırda kaldığı sürece)
+    fn location_from(&self, start: SourceLocation) -> SourceLocation {
+        match &self.previous_token {
+            Some(last) if last.line == start.line && last.column >= start.column => {
+                SourceLocation { length: last.column + last.length - start.column, ..start }
+            }
+            _ => start,
+        }
+    }
+
+    // Geçerli token'dan sonraki n'inci token (0: hemen sonraki); tüketmez
+    fn peek_nth(&mut self, n: usize) -> &Token {
+        while self.lookahead.len() <= n {
+            let token = self.read_token();
+            self.lookahead.push_back(token);
+        }
+        &self.lookahead[n]
+    }
+
+    // Geçerli token bir etiket tanımının ("AD:") başlangıcı mı?
+    fn at_label(&mut self) -> bool {
+        matches!(self.current_token.token_type, TokenType::Identifier(_)) && self.peek_nth(0).token_type == TokenType::Colon
+    }
+
+    // Sabit tanımı veya atama ("AD = ifade") mı?
+    fn at_assignment(&mut self) -> bool {
+        matches!(self.current_token.token_type, TokenType::Identifier(_))
+            && self.peek_nth(0).token_type == TokenType::Equals
+    }
+
+    // Hatalı bir deyimden sonra ayrıştırmanın güvenle sürebileceği ilk token'a atlar:
+    // bir sonraki satır, aynı satırdaki bir etiket ya da bir direktif
+    fn synchronize(&mut self, line: usize) {
+        while self.current_token.token_type != TokenType::EndOfFile && self.current_token.line == line {
+            let at_directive = matches!(&self.current_token.token_type, TokenType::Keyword(k) if k == "GLOBAL" || k == "EXTERN")
+                || matches!(self.current_token.token_type, TokenType::Directive(_));
+            if at_directive || self.at_label() {
+                return;
+            }
+            self.advance();
+        }
+    }
+
+    fn expect_end_of_line(&mut self, line: usize) {
+        let at_end = matches!(self.current_token.token_type, TokenType::Newline | TokenType::EndOfFile);
+        if !at_end && self.current_token.line == line {
+            match &self.current_token.token_type {
+                // Tanınmayan karakter kendi koduyla bildirilir
+                TokenType::Unknown(ch) => {
+                    let ch = *ch;
+                    self.error(DiagnosticCode::UnexpectedCharacter, msg!("B0101", ch));
+                }
+                other => {
+                    let found = other.describe();
+                    self.error(DiagnosticCode::ExpectedEndOfLine, msg!("B0110", found));
+                }
+            }
+            self.synchronize(line);
+        }
+    }
+
+    fn consume(&mut self, expected_type: TokenType) -> Option<()> {
+        if self.current_token.token_type == expected_type {
+            self.advance();
+            Some(())
+        } else {
+            self.error(DiagnosticCode::UnexpectedToken, msg!("B0103", expected_type.describe(), self.current_token.token_type.describe()));
+            None
+        }
+    }
+
+    // Deyimin en dıştaki ifadeleri konumlarıyla birlikte kaydedilir; semantik
+    // tanılamalar bütün satırı değil ilgili işleneni gösterir
+    fn parse_expression(&mut self) -> Option<Expression> {
+        let start = self.current_token.location();
+        self.expression_depth += 1;
+        let expr = self.parse_sum();
+        self.expression_depth -= 1;
+        if expr.is_some() && self.expression_depth == 0 {
+            let location = self.location_from(start);
+            self.operands.push(location);
+        }
+        expr
+    }
+
+    // İşlenen, ardından isteğe bağlı '+'/'-' zinciri (soldan birleşir)
+    fn parse_sum(&mut self) -> Option<Expression> {
+        let mut expr = self.parse_primary()?;
+        loop {
+            let operator = match self.current_token.token_type {
+                TokenType::Plus => BinaryOperator::Add,
+                TokenType::Minus => BinaryOperator::Subtract,
+                _ => return Some(expr),
+            };
+            self.advance();
+            let right = self.parse_primary()?;
+            expr = Expression::Binary { operator, left: Box::new(expr), right: Box::new(right) };
+        }
+    }
+
+    fn parse_primary(&mut self) -> Option<Expression> {
+        let expr = match &self.current_token.token_type {
+            TokenType::Identifier(name) => Expression::Identifier(name.clone()),
+            TokenType::Number(value) => Expression::Number(*value),
+            TokenType::InvalidNumber(text, error) => {
+                // String hatalarında olduğu gibi deyim ayrıştırılmaya devam eder
+                let (code, message) = number_error(text, *error);
+                self.error(code, message);
+                Expression::Number(0)
+            }
+            TokenType::Flag(flag) => Expression::Flag(flag.clone()),
+            TokenType::StringLiteral(s) => Expression::StringLiteral(s.clone()),
+            TokenType::Register(index) => Expression::Register(*index),
+            TokenType::Dollar => {
+                self.advance();
+                return match &self.current_token.token_type {
+                    TokenType::Identifier(name) => {
+                        let expr = Expression::AddressOf(name.clone());
+                        self.advance();
+                        Some(expr)
+                    }
+                    _ => {
+                        self.error(DiagnosticCode::MissingOperand, msg!("B0105.address", self.current_token.token_type.describe()));
+                        None
+                    }
+                };
+            }
+            TokenType::LocationCounter => Expression::LocationCounter,
+            TokenType::OpenBracket => {
+                self.advance();
+                let address = self.parse_expression()?;
+                self.consume(TokenType::CloseBracket)?;
+                return Some(Expression::Memory(Box::new(address)));
+            }
+            TokenType::InvalidString(s, errors) => {
+                // Her geçersiz kaçış kendi konumunda raporlanır; string geri kalanıyla kullanılır
+                let location = self.current_token.location();
+                for error in errors {
+                    let escape = SourceLocation { column: error.column, length: error.length, ..location };
+                    self.error_reporter.report_error_at(DiagnosticCode::InvalidEscape, escape_message(error.kind), Some(escape));
+                }
+                Expression::StringLiteral(s.clone())
+            }
+            TokenType::UnterminatedString(s) => {
+                // Hata raporlanır ama deyim ayrıştırılmaya devam eder; string zaten satır sonunda bitti
+                let expr = Expression::StringLiteral(s.clone());
+                let location = self.current_token.location();
+                let end = SourceLocation { column: location.column + location.length, length: 1, ..location };
+                let mut diagnostic = Diagnostic::error(DiagnosticCode::UnterminatedString, msg!("B0102"), Some(location))
+                    .with_label(end, msg!("label.string-end"));
+                // Değerdeki tırnak ancak '\"' kaçışından gelebilir; büyük olasılıkla kapanış tırnağı sanılmıştır
+                if s.contains('"') {
+                    diagnostic = diagnostic.with_help(msg!("help.escaped-quote"));
+                }
+                self.error_reporter.report(diagnostic);
+                expr
+            }
+            _ => {
+                self.error(DiagnosticCode::ExpectedExpression, msg!("B0104", self.current_token.token_type.describe()));
+                return None;
+            }
+        };
+        self.advance();
+        Some(expr)
+    }
+
+    // Geçerli token önceki token'la aynı satırda bir işlenen başlatıyor mu?
+    fn at_operand(&self) -> bool {
+        let same_line = self.previous_token.as_ref().is_none_or(|previous| previous.line == self.current_token.line);
+        same_line
+            && matches!(
+                self.current_token.token_type,
+                TokenType::Identifier(_) | TokenType::Number(_) | TokenType::InvalidNumber(..) | TokenType::Flag(_) | TokenType::StringLiteral(_) | TokenType::InvalidString(..) | TokenType::UnterminatedString(_) | TokenType::Register(_) | TokenType::Dollar | TokenType::LocationCounter | TokenType::OpenBracket
+            )
+    }
+
+    // EXIT gibi isteğe bağlı operand alan komutlar için
+    fn parse_optional_expression(&mut self) -> Option<Expression> {
+        if self.at_operand() {
+            self.parse_expression()
+        } else {
+            None
+        }
+    }
+
+    // Genel komut: virgülle ayrılmış işlenenlerin sayısı ve biçimleri komut
+    // tablosundaki imzaya göre denetlenir
+    fn parse_instruction(&mut self, opcode: String) -> Option<AstNode> {
+        let slots = instructions::signature(&opcode)?;
+        let opcode_location = self.current_token.location();
+        self.advance();
+        let mut operands = Vec::new();
+        let mut locations = Vec::new();
+        if self.at_operand() {
+            loop {
+                locations.push(self.current_token.location());
+                operands.push(self.parse_expression()?);
+                if self.current_token.token_type != TokenType::Comma {
+                    break;
+                }
+                self.advance();
+            }
+        }
+        if operands.len() != slots.len() {
+            // Fazla işlenenler ilk fazla işlenende, eksikler satırda kalan ilk token'da ya da
+            // satır bittiyse son işlenende (işlenen yoksa komutta) raporlanır
+            let on_line = self.current_token.line == opcode_location.line
+                && !matches!(self.current_token.token_type, TokenType::Newline | TokenType::EndOfFile);
+            let location = match locations.get(slots.len()) {
+                Some(extra) => *extra,
+                None if on_line => self.current_token.location(),
+                None => locations.last().copied().unwrap_or(opcode_location),
+            };
+            let message = msg!("B0305.instruction", &opcode, slots.len(), operands.len());
+            self.error_reporter.report_error_at(DiagnosticCode::OperandCount, message, Some(location));
+            return None;
+        }
+        let mut valid = true;
+        for (index, (operand, slot)) in operands.iter().zip(slots).enumerate() {
+            let kind = OperandKind::of(operand);
+            if !slot.accepts(kind) {
+                let message = msg!("B0306.instruction", &opcode, index + 1, Arg::Key(slot.key()), Arg::Key(kind.key()));
+                self.error_reporter.report_error_at(DiagnosticCode::InvalidOperand, message, Some(locations[index]));
+                valid = false;
+            }
+        }
+        if !valid {
+            return None;
+        }
+        match (opcode.as_str(), operands.as_slice()) {
+            // JMP, JUMP deyimiyle aynı düğüme indirgenir; akış analizi ve kod üretimi ikisini ayırt etmez
+            ("JMP", [Expression::Identifier(target)]) => Some(AstNode::JumpStatement { target: target.clone() }),
+            _ => Some(AstNode::Instruction { opcode, operands }),
+        }
+    }
+
+    // Virgülle ayrılmış iki işlenen (CTRL, SEND)
+    fn parse_expression_pair(&mut self) -> Option<(Expression, Expression)> {
+        let first = self.parse_expression()?;
+        self.consume(TokenType::Comma)?;
+        let second = self.parse_expression()?;
+        Some((first, second))
+    }
+
+    // Deyimin adlandırdığı sembol (handle, hedef, prosedür); ad yoksa B0105 raporlanır
+    fn parse_name(&mut self, missing: impl FnOnce(Arg) -> Message) -> Option<String> {
+        match &self.current_token.token_type {
+            TokenType::Identifier(name) => {
+                let name = name.clone();
+                self.advance();
+                Some(name)
+            }
+            other => {
+                let message = missing(other.describe());
+                self.error(DiagnosticCode::MissingOperand, message);
+                None
+            }
+        }
+    }
+
+    fn parse_statement(&mut self) -> Option<AstNode> {
+        if self.at_assignment() {
+            return self.parse_assignment();
+        }
+        match &self.current_token.token_type {
+            TokenType::Keyword(keyword) => match keyword.as_str() {
+                "JUMP" => {
+                    self.advance();
+                    let target = self.parse_name(|found| msg!("B0105.jump", found))?;
+                    Some(AstNode::JumpStatement { target })
+                }
+                "FLAG" => {
+                    self.advance();
+                    match &self.current_token.token_type {
+                        TokenType::Flag(flag) => {
+                            let node = AstNode::FlagDeclaration { flag: flag.clone() };
+                            self.advance();
+                            Some(node)
+                        }
+                        _ => {
+                            self.error(DiagnosticCode::MissingOperand, msg!("B0105.flag", self.current_token.token_type.describe()));
+                            None
+                        }
+                    }
+                }
+                "ALLOCATE" => {
+                    self.advance();
+                    let size = self.parse_expression()?;
+                    self.consume(TokenType::Keyword("AS".to_string()))?;
+                    let handle = self.parse_name(|found| msg!("B0105.allocate", found))?;
+                    Some(AstNode::AllocateMemory { size, handle })
+                }
+                "RELEASE" => {
+                    self.advance();
+                    let handle = self.parse_expression()?;
+                    Some(AstNode::ReleaseMemory { handle })
+                }
+                "SPAWN" => {
+                    self.advance();
+                    let function = self.parse_name(|found| msg!("B0105.spawn", found))?;
+                    let mut priority = None;
+                    if self.current_token.token_type == TokenType::Keyword("WITH".to_string()) {
+                        self.advance();
+                        self.consume(TokenType::Identifier("prio".to_string()))?;
+                        self.consume(TokenType::Equals)?;
+                        priority = Some(self.parse_expression()?);
+                    }
+                    Some(AstNode::SpawnTask { function, priority })
+                }
+                "EXIT" => {
+                    self.advance();
+                    let code = self.parse_optional_expression();
+                    Some(AstNode::ExitTask { code })
+                }
+                "SLEEP" => {
+                    self.advance();
+                    let duration = self.parse_expression()?;
+                    Some(AstNode::SleepTask { duration })
+                }
+                "YIELD" => {
+                    self.advance();
+                    Some(AstNode::YieldTask)
+                }
+                "ACQUIRE" => {
+                    self.advance();
+                    let name = self.parse_expression()?;
+                    self.consume(TokenType::Keyword("AS".to_string()))?;
+                    let handle = self.parse_name(|found| msg!("B0105.acquire", found))?;
+                    Some(AstNode::AcquireResource { name, handle })
+                }
+                "CTRL" => {
+                    self.advance();
+                    let (handle, command) = self.parse_expression_pair()?;
+                    Some(AstNode::ControlResource { handle, command })
+                }
+                "SEND" => {
+                    self.advance();
+                    let (handle, message) = self.parse_expression_pair()?;
+                    Some(AstNode::SendMessage { handle, message })
+                }
+                "RECV" => {
+                    self.advance();
+                    let handle = self.parse_expression()?;
+                    self.consume(TokenType::Comma)?;
+                    let buffer = self.parse_name(|found| msg!("B0105.recv", found))?;
+                    Some(AstNode::ReceiveMessage { handle, buffer })
+                }
+                // "VAR ad tip": bir bildirim olduğundan işlenenler virgülsüz yazılır
+                "VAR" => {
+                    self.advance();
+                    let mut operands = Vec::new();
+                    while operands.len() < 2 && self.at_operand() {
+                        match &self.current_token.token_type {
+                            TokenType::Identifier(name) => {
+                                operands.push(Expression::Identifier(name.clone()));
+                                self.advance();
+                            }
+                            _ => {
+                                self.error(DiagnosticCode::InvalidOperand, msg!("B0306.var"));
+                                return None;
+                            }
+                        }
+                    }
+                    if operands.len() < 2 {
+                        self.error(DiagnosticCode::OperandCount, msg!("B0305.var"));
+                        return None;
+                    }
+                    Some(AstNode::Instruction { opcode: "VAR".to_string(), operands })
+                }
+                "GET_TASK_ID" | "GET_CORE_ID" | "GET_TOTAL_CORES" => {
+                    let keyword = keyword.clone();
+                    self.advance();
+                    let target = self.parse_name(|found| msg!("B0105.target", &keyword, found))?;
+                    Some(match keyword.as_str() {
+                        "GET_TASK_ID" => AstNode::GetTaskId { target },
+                        "GET_CORE_ID" => AstNode::GetCoreId { target },
+                        _ => AstNode::GetTotalCores { target },
+                    })
+                }
+                // Dosyalar arası semboller: bağlayıcı tarafından çözümlenir
+                "GLOBAL" | "EXTERN" => {
+                    let opcode = keyword.clone();
+                    self.advance();
+                    match &self.current_token.token_type {
+                        TokenType::Identifier(name) => {
+                            let node = AstNode::Instruction { opcode, operands: vec![Expression::Identifier(name.clone())] };
+                            self.advance();
+                            Some(node)
+                        }
+                        _ => {
+                            self.error(DiagnosticCode::MissingOperand, msg!("B0105.directive", opcode, self.current_token.token_type.describe()));
+                            None
+                        }
+                    }
+                }
+                _ => None,
+            },
+            TokenType::Mnemonic(opcode) => {
+                let opcode = opcode.clone();
+                self.parse_instruction(opcode)
+            }
+            TokenType::Directive(name) => {
+                let name = name.clone();
+                self.parse_directive(&name)
+            }
+            TokenType::Identifier(name) => {
+                let name = name.clone();
+                if !self.at_label() {
+                    // Etiket olmayan bir ad deyim başında yalnızca yanlış yazılmış bir komut olabilir
+                    self.error(DiagnosticCode::UnexpectedToken, msg!("B0103.instruction", name));
+                    return None;
+                }
+                self.advance();
+                self.advance(); // ':'
+                Some(AstNode::Label { name })
+            }
+            TokenType::Unknown(ch) => {
+                self.error(DiagnosticCode::UnexpectedCharacter, msg!("B0101", ch));
+                None
+            }
+            _ => None,
+        }
+    }
+
+    // "ad = ifade": veri bölümünde sabit tanımı, kod bölümünde değişkene atama
+    fn parse_assignment(&mut self) -> Option<AstNode> {
+        let name = match &self.current_token.token_type {
+            TokenType::Identifier(name) => name.clone(),
+            _ => return None,
+        };
+        self.advance();
+        self.advance(); // '='
+        let value = self.parse_expression()?;
+        Some(match self.section {
+            Section::Data => AstNode::Constant { name, value },
+            Section::Code => AstNode::Assignment { variable: name, value },
+        })
+    }
+
+    // Geçerli token '.ad' direktifi; ad büyük/küçük harf duyarsızdır
+    fn parse_directive(&mut self, name: &str) -> Option<AstNode> {
+        let lowercase = name.to_ascii_lowercase();
+        match lowercase.as_str() {
+            "data" | "code" => {
+                self.section = if lowercase == "data" { Section::Data } else { Section::Code };
+                self.advance();
+                Some(AstNode::Section { section: self.section })
+            }
+            // GLOBAL/EXTERN anahtar kelimeleriyle aynı anlam
+            "global" | "extern" => {
+                self.advance();
+                match &self.current_token.token_type {
+                    TokenType::Identifier(symbol) => {
+                        let node = AstNode::Instruction { opcode: lowercase.to_ascii_uppercase(), operands: vec![Expression::Identifier(symbol.clone())] };
+                        self.advance();
+                        Some(node)
+                    }
+                    _ => {
+                        self.error(DiagnosticCode::MissingOperand, msg!("B0105.directive", format!(".{}", name), self.current_token.token_type.describe()));
+                        None
+                    }
+                }
+            }
+            _ => match DataKind::from_name(&lowercase) {
+                Some(kind) => {
+                    self.advance();
+                    let mut values = vec![self.parse_expression()?];
+                    while self.current_token.token_type == TokenType::Comma {
+                        self.advance();
+                        values.push(self.parse_expression()?);
+                    }
+                    Some(AstNode::DataDirective { kind, values })
+                }
+                None => {
+                    self.error(DiagnosticCode::UnknownDirective, msg!("B0106", name));
+                    None
+                }
+            },
+        }
+    }
+
+    pub fn parse_program(&mut self) -> AstNode {
+        let mut statements = Vec::new();
+        while self.current_token.token_type != TokenType::EndOfFile {
+            if self.current_token.token_type == TokenType::Newline {
+                self.advance(); // Boş satır veya önceki deyimin satır sonu
+                continue;
+            }
+            let location = self.current_token.location();
+            let error_count = self.error_reporter.diagnostics().len();
+            self.operands.clear();
+            match self.parse_statement() {
+                Some(node) => {
+                    // Etiketten sonra aynı satırda bir deyim gelebilir; diğer deyimler satırı bitirir
+                    let is_label = matches!(node, AstNode::Label { .. });
+                    let operands = std::mem::take(&mut self.operands);
+                    statements.push(Statement { node, location: self.location_from(location), operands });
+                    if !is_label {
+                        self.expect_end_of_line(location.line);
+                    }
+                }
+                // Hatalı deyimin geri kalanını atlayıp bir sonraki deyimden devam et
+                None => {
+                    if self.error_reporter.diagnostics().len() == error_count {
+                        // Hiçbir deyimi başlatmayan token (ör. '5', ',' veya tek başına 'WITH')
+                        self.error(DiagnosticCode::UnexpectedToken, msg!("B0103", Arg::Key("token.statement"), self.current_token.token_type.describe()));
+                    }
+                    if self.current_token.location() == location {
+                        self.advance(); // İlerleme garantisi: hatalı token en az bir kez tüketilir
+                    }
+                    self.synchronize(location.line);
+                }
+            }
+        }
+        AstNode::Program(statements)
+    }
+}
+
+// Geçersiz sayı sabitinin tanılama kodu ve mesajı
+fn number_error(text: &str, error: NumberError) -> (DiagnosticCode, Message) {
+    match error {
+        NumberError::Overflow => (DiagnosticCode::NumberOverflow, msg!("B0108", text)),
+        NumberError::InvalidDigit(digit) => (DiagnosticCode::InvalidNumber, msg!("B0107", digit, text)),
+        NumberError::MissingDigits => (DiagnosticCode::InvalidNumber, msg!("B0107.digits", text)),
+        NumberError::CharacterLength => (DiagnosticCode::InvalidNumber, msg!("B0107.character", text)),
+        NumberError::UnterminatedCharacter => (DiagnosticCode::InvalidNumber, msg!("B0107.unterminated", text)),
+        NumberError::Escape(kind) => (DiagnosticCode::InvalidEscape, escape_message(kind)),
+    }
+}
+
+fn escape_message(kind: EscapeErrorKind) -> Message {
+    match kind {
+        EscapeErrorKind::Unknown(ch) => msg!("B0109", ch),
+        EscapeErrorKind::InvalidHex => msg!("B0109.hex"),
+        EscapeErrorKind::HexOutOfRange => msg!("B0109.hex-range"),
+        EscapeErrorKind::InvalidUnicode => msg!("B0109.unicode"),
+        EscapeErrorKind::UnicodeOutOfRange => msg!("B0109.unicode-range"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Programın deyimlerini ve "satır:sütun mesaj" biçimindeki hataları döndürür
+    fn parse(input: &str) -> (Vec<AstNode>, Vec<String>) {
+        let mut parser = Parser::new(Lexer::new(input.to_string()));
+        let statements = match parser.parse_program() {
+            AstNode::Program(statements) => statements.into_iter().map(|s| s.node).collect(),
+            _ => unreachable!(),
+        };
+        let errors = parser
+            .diagnostics()
+            .iter()
+            .map(|d| {
+                let location = d.location.expect("sözdizimi hataları konum taşır");
+                format!("{}:{} {}", location.line, location.column, d.message)
+            })
+            .collect();
+        (statements, errors)
+    }
+
+    #[test]
+    fn test_recovery_after_bad_operand() {
+        let (statements, errors) = parse("JUMP 5 LOOP: RELEASE 1\nFLAG 7 FLAG Z\nRELEASE 2");
+        assert_eq!(errors, vec![
+            "1:6 JUMP komutundan sonra hedef bekleniyor, '5' bulundu",
+            "2:6 FLAG komutundan sonra flag bekleniyor, '7' bulundu",
+        ]);
+        assert_eq!(statements, vec![
+            AstNode::Label { name: "LOOP".to_string() },
+            AstNode::ReleaseMemory { handle: Expression::Number(1) },
+            AstNode::ReleaseMemory { handle: Expression::Number(2) },
+        ]);
+    }
+
+    #[test]
+    fn test_recovery_after_missing_as() {
+        let (statements, errors) = parse("ALLOCATE 1024 MEM1\nALLOCATE 64 AS MEM2");
+        assert_eq!(errors, vec!["1:15 'AS' bekleniyor, 'MEM1' bulundu"]);
+        assert_eq!(statements, vec![AstNode::AllocateMemory { size: Expression::Number(64), handle: "MEM2".to_string() }]);
+    }
+
+    #[test]
+    fn test_sections_data_directives_and_location_counter() {
+        let (statements, errors) = parse(concat!(
+            ".data ; veri bölümü\n",
+            "MSG:   .string \"Merhaba\"\n",
+            "MSG_LEN = . - MSG + 1\n",
+            "TABLE: .byte 1, 2, 3\n",
+            ".code\n",
+            ".global START\n",
+            ".dta\n",
+            "START:\n",
+        ));
+        assert_eq!(errors, vec!["7:1 Bilinmeyen direktif '.dta'"]);
+        let location_counter_minus_msg = Expression::Binary {
+            operator: BinaryOperator::Subtract,
+            left: Box::new(Expression::LocationCounter),
+            right: Box::new(Expression::Identifier("MSG".to_string())),
+        };
+        assert_eq!(statements, vec![
+            AstNode::Section { section: Section::Data },
+            AstNode::Label { name: "MSG".to_string() },
+            AstNode::DataDirective { kind: DataKind::String, values: vec![Expression::StringLiteral("Merhaba".to_string())] },
+            AstNode::Constant {
+                name: "MSG_LEN".to_string(),
+                value: Expression::Binary {
+                    operator: BinaryOperator::Add,
+                    left: Box::new(location_counter_minus_msg),
+                    right: Box::new(Expression::Number(1)),
+                },
+            },
+            AstNode::Label { name: "TABLE".to_string() },
+            AstNode::DataDirective { kind: DataKind::Byte, values: vec![Expression::Number(1), Expression::Number(2), Expression::Number(3)] },
+            AstNode::Section { section: Section::Code },
+            AstNode::Instruction { opcode: "GLOBAL".to_string(), operands: vec![Expression::Identifier("START".to_string())] },
+            AstNode::Label { name: "START".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn test_minus_after_an_operand_is_subtraction() {
+        let (statements, errors) = parse(".data\nL = .-1\n.code\nLOAD R0, [R1-4]\nLOAD R0, MSG-1\nLOAD R0, -1");
+        assert!(errors.is_empty(), "{:?}", errors);
+        let subtract = |left: Expression, right: i64| Expression::Binary {
+            operator: BinaryOperator::Subtract,
+            left: Box::new(left),
+            right: Box::new(Expression::Number(right)),
+        };
+        assert_eq!(statements, vec![
+            AstNode::Section { section: Section::Data },
+            AstNode::Constant { name: "L".to_string(), value: subtract(Expression::LocationCounter, 1) },
+            AstNode::Section { section: Section::Code },
+            AstNode::Instruction {
+                opcode: "LOAD".to_string(),
+                operands: vec![Expression::Register(0), Expression::Memory(Box::new(subtract(Expression::Register(1), 4)))],
+            },
+            AstNode::Instruction {
+                opcode: "LOAD".to_string(),
+                operands: vec![Expression::Register(0), subtract(Expression::Identifier("MSG".to_string()), 1)],
+            },
+            AstNode::Instruction { opcode: "LOAD".to_string(), operands: vec![Expression::Register(0), Expression::Number(-1)] },
+        ]);
+    }
+
+    #[test]
+    fn test_recovery_after_unterminated_string() {
+        let (statements, errors) = parse("RELEASE \"abc\nRELEASE 3");
+        assert_eq!(errors, vec!["1:9 Kapatılmamış string sabiti; satır sonundan önce '\"' bekleniyor"]);
+        assert_eq!(statements, vec![
+            AstNode::ReleaseMemory { handle: Expression::StringLiteral("abc".to_string()) },
+            AstNode::ReleaseMemory { handle: Expression::Number(3) },
+        ]);
+    }
+
+    #[test]
+    fn test_invalid_number_literals_are_reported_and_skipped() {
+        let (statements, errors) = parse("RELEASE 0b102\nRELEASE 0x\nRELEASE 0x1_0000_0000_0000_0000\nRELEASE 'AB'\nRELEASE 'A\nRELEASE 0o17");
+        assert_eq!(errors, vec![
+            "1:9 '0b102' sayı sabitinde geçersiz basamak '2'",
+            "2:9 '0x' sayı sabitinde basamak yok",
+            "3:9 '0x1_0000_0000_0000_0000' sayı sabiti 64 bite sığmıyor",
+            "4:9 'AB' karakter sabiti tam olarak bir karakter içermeli",
+            "5:9 Kapatılmamış karakter sabiti 'A; satır sonundan önce \"'\" bekleniyor",
+        ]);
+        assert_eq!(statements.len(), 6);
+        assert_eq!(statements[5], AstNode::ReleaseMemory { handle: Expression::Number(0o17) });
+    }
+
+    #[test]
+    fn test_invalid_escapes_are_reported_at_the_escape() {
+        let (statements, errors) = parse("RELEASE \"a\\qb\\x4\"\nRELEASE \"C:\\\"\nRELEASE \"\\u{41\"");
+        assert_eq!(errors, vec![
+            "1:11 Bilinmeyen kaçış dizisi '\\q'",
+            "1:14 '\\x' kaçışından sonra iki onaltılık basamak bekleniyor",
+            "2:9 Kapatılmamış string sabiti; satır sonundan önce '\"' bekleniyor",
+            "3:10 '\\u' kaçışı '\\u{1F600}' biçiminde 1-6 onaltılık basamak bekler",
+        ]);
+        assert_eq!(statements[0], AstNode::ReleaseMemory { handle: Expression::StringLiteral("ab".to_string()) });
+    }
+
+    #[test]
+    fn test_one_statement_per_line() {
+        let (statements, errors) = parse("LOOP: RELEASE 1\n\nRELEASE 2 RELEASE 3\nRELEASE 4 ; yorum\nJUMP LOOP");
+        assert_eq!(errors, vec!["3:11 Deyimden sonra satır sonu bekleniyor, 'RELEASE' bulundu"]);
+        assert_eq!(statements, vec![
+            AstNode::Label { name: "LOOP".to_string() },
+            AstNode::ReleaseMemory { handle: Expression::Number(1) },
+            AstNode::ReleaseMemory { handle: Expression::Number(2) },
+            AstNode::ReleaseMemory { handle: Expression::Number(4) },
+            AstNode::JumpStatement { target: "LOOP".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn test_lookahead_and_token_stream_constructor() {
+        let mut parser = Parser::from_source("MAX = 4\nLOOP: RELEASE 1");
+        assert_eq!(parser.peek_nth(0).token_type, TokenType::Equals);
+        assert_eq!(parser.peek_nth(3).token_type, TokenType::Identifier("LOOP".to_string()));
+        assert_eq!(parser.peek_nth(4).token_type, TokenType::Colon);
+        assert_eq!(parser.peek_nth(9).token_type, TokenType::EndOfFile);
+        assert_eq!(parser.current_token.token_type, TokenType::Identifier("MAX".to_string()));
+
+        // Makro genişletmesi gibi Newline ve EndOfFile içermeyen bir dizi
+        let tokens: Vec<Token> = Lexer::new("LOOP: RELEASE 1\nJUMP LOOP".to_string())
+            .filter(|token| token.token_type != TokenType::EndOfFile)
+            .collect();
+        let mut parser = Parser::from_tokens(tokens);
+        let statements = match parser.parse_program() {
+            AstNode::Program(statements) => statements,
+            _ => unreachable!(),
+        };
+        assert!(parser.diagnostics().is_empty());
+        assert_eq!(statements.iter().map(|s| s.node.clone()).collect::<Vec<_>>(), vec![
+            AstNode::Label { name: "LOOP".to_string() },
+            AstNode::ReleaseMemory { handle: Expression::Number(1) },
+            AstNode::JumpStatement { target: "LOOP".to_string() },
+        ]);
+        assert_eq!(statements[2].location, SourceLocation { line: 2, column: 1, length: 9 });
+    }
+
+    #[test]
+    fn test_every_statement_form() {
+        let name = |value: &str| value.to_string();
+        let identifier = |value: &str| Expression::Identifier(value.to_string());
+        let cases = vec![
+            ("JUMP LOOP", vec![AstNode::JumpStatement { target: name("LOOP") }]),
+            ("FLAG ZF", vec![AstNode::FlagDeclaration { flag: name("ZF") }]),
+            ("ALLOCATE 64 AS MEM", vec![AstNode::AllocateMemory { size: Expression::Number(64), handle: name("MEM") }]),
+            ("RELEASE MEM", vec![AstNode::ReleaseMemory { handle: identifier("MEM") }]),
+            ("SPAWN WORKER", vec![AstNode::SpawnTask { function: name("WORKER"), priority: None }]),
+            ("SPAWN WORKER WITH prio=2", vec![AstNode::SpawnTask { function: name("WORKER"), priority: Some(Expression::Number(2)) }]),
+            ("EXIT", vec![AstNode::ExitTask { code: None }]),
+            ("EXIT 1", vec![AstNode::ExitTask { code: Some(Expression::Number(1)) }]),
+            ("SLEEP 100", vec![AstNode::SleepTask { duration: Expression::Number(100) }]),
+            ("YIELD", vec![AstNode::YieldTask]),
+            (
+                "ACQUIRE \"sahne://console\" AS CONSOLE",
+                vec![AstNode::AcquireResource { name: Expression::StringLiteral(name("sahne://console")), handle: name("CONSOLE") }],
+            ),
+            ("CTRL CONSOLE, 1", vec![AstNode::ControlResource { handle: identifier("CONSOLE"), command: Expression::Number(1) }]),
+            ("SEND CONSOLE, $MSG", vec![AstNode::SendMessage { handle: identifier("CONSOLE"), message: Expression::AddressOf(name("MSG")) }]),
+            ("RECV CONSOLE, BUFFER", vec![AstNode::ReceiveMessage { handle: identifier("CONSOLE"), buffer: name("BUFFER") }]),
+            ("GET_TASK_ID TASK", vec![AstNode::GetTaskId { target: name("TASK") }]),
+            ("GET_CORE_ID CORE", vec![AstNode::GetCoreId { target: name("CORE") }]),
+            ("GET_TOTAL_CORES CORES", vec![AstNode::GetTotalCores { target: name("CORES") }]),
+            ("GLOBAL START", vec![AstNode::Instruction { opcode: name("GLOBAL"), operands: vec![identifier("START")] }]),
+            ("START:", vec![AstNode::Label { name: name("START") }]),
+            ("VAR COUNT DWORD", vec![AstNode::Instruction { opcode: name("VAR"), operands: vec![identifier("COUNT"), identifier("DWORD")] }]),
+            ("load R1, $MSG", vec![AstNode::Instruction { opcode: name("LOAD"), operands: vec![Expression::Register(1), Expression::AddressOf(name("MSG"))] }]),
+            (
+                "MOV [R1 + 4], R2",
+                vec![AstNode::Instruction {
+                    opcode: name("MOV"),
+                    operands: vec![
+                        Expression::Memory(Box::new(Expression::Binary {
+                            operator: BinaryOperator::Add,
+                            left: Box::new(Expression::Register(1)),
+                            right: Box::new(Expression::Number(4)),
+                        })),
+                        Expression::Register(2),
+                    ],
+                }],
+            ),
+            ("JLT DONE", vec![AstNode::Instruction { opcode: name("JLT"), operands: vec![identifier("DONE")] }]),
+            ("RET", vec![AstNode::Instruction { opcode: name("RET"), operands: vec![] }]),
+            (
+                "COUNT = COUNT + 1",
+                vec![AstNode::Assignment {
+                    variable: name("COUNT"),
+                    value: Expression::Binary { operator: BinaryOperator::Add, left: Box::new(identifier("COUNT")), right: Box::new(Expression::Number(1)) },
+                }],
+            ),
+            (
+                ".data\nSIZE = 4\nTABLE: .byte 1",
+                vec![
+                    AstNode::Section { section: Section::Data },
+                    AstNode::Constant { name: name("SIZE"), value: Expression::Number(4) },
+                    AstNode::Label { name: name("TABLE") },
+                    AstNode::DataDirective { kind: DataKind::Byte, values: vec![Expression::Number(1)] },
+                ],
+            ),
+        ];
+        for (source, expected) in cases {
+            let (statements, errors) = parse(source);
+            assert!(errors.is_empty(), "{}: {:?}", source, errors);
+            assert_eq!(statements, expected, "{}", source);
+        }
+    }
+
+    #[test]
+    fn test_missing_operands_of_task_and_resource_statements() {
+        let (statements, errors) = parse("SLEEP\nACQUIRE \"konsol\" AS 5\nCTRL MEM 1\nRECV MEM, 5\nGET_CORE_ID 7\nEXIT\nYIELD");
+        assert_eq!(errors, vec![
+            "1:6 İfade bekleniyor, satır sonu bulundu",
+            "2:21 ACQUIRE komutunda AS sonrasında handle adı bekleniyor, '5' bulundu",
+            "3:10 ',' bekleniyor, '1' bulundu",
+            "4:11 RECV komutunda virgülden sonra buffer adı bekleniyor, '5' bulundu",
+            "5:13 GET_CORE_ID komutundan sonra hedef değişken adı bekleniyor, '7' bulundu",
+        ]);
+        assert_eq!(statements, vec![AstNode::ExitTask { code: None }, AstNode::YieldTask]);
+
+        // Newline token'ı olmayan dizide EXIT sonraki satırdaki adı çıkış kodu sanmaz
+        let tokens: Vec<Token> = Lexer::new("EXIT\nDONE:".to_string()).collect();
+        let statements = match Parser::from_tokens(tokens).parse_program() {
+            AstNode::Program(statements) => statements.into_iter().map(|s| s.node).collect::<Vec<_>>(),
+            _ => unreachable!(),
+        };
+        assert_eq!(statements, vec![AstNode::ExitTask { code: None }, AstNode::Label { name: "DONE".to_string() }]);
+    }
+
+    #[test]
+    fn test_tokens_that_start_no_statement_are_reported() {
+        let (statements, errors) = parse("5\n, ,\nMACRO foo\nWITH prio=1\nYIELD");
+        assert_eq!(errors, vec![
+            "1:1 deyim bekleniyor, '5' bulundu",
+            "2:1 deyim bekleniyor, ',' bulundu",
+            "3:1 deyim bekleniyor, 'MACRO' bulundu",
+            "4:1 deyim bekleniyor, 'WITH' bulundu",
+        ]);
+        assert_eq!(statements, vec![AstNode::YieldTask]);
+    }
+
+    #[test]
+    fn test_unknown_instruction_is_reported() {
+        let (statements, errors) = parse("LAOD R0, 5\nJMPZ DONE\nJMP DONE\nDONE:");
+        assert_eq!(errors, vec!["1:1 Bilinmeyen komut 'LAOD'", "2:1 Bilinmeyen komut 'JMPZ'"]);
+        assert_eq!(statements, vec![
+            AstNode::JumpStatement { target: "DONE".to_string() },
+            AstNode::Label { name: "DONE".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn test_instruction_operands_are_checked_against_the_table() {
+        let (statements, errors) = parse("LOAD R0\nREAD 5\nRET 1\nWRITE \"tamam\"\nCALL R1\nADD COUNT 1\nNOT ; yorum");
+        assert_eq!(errors, vec![
+            "1:6 LOAD komutu 2 işlenen alır, 1 verildi",
+            "2:6 READ komutunun 1. işleneni bir register, değişken veya bellek başvurusu olmalı, sabit değer bulundu",
+            "3:5 RET komutu 0 işlenen alır, 1 verildi",
+            "5:6 CALL komutunun 1. işleneni bir prosedür adı olmalı, register bulundu",
+            "6:11 ADD komutu 2 işlenen alır, 1 verildi",
+            "7:1 NOT komutu 1 işlenen alır, 0 verildi",
+        ]);
+        assert_eq!(statements, vec![AstNode::Instruction { opcode: "WRITE".to_string(), operands: vec![Expression::StringLiteral("tamam".to_string())] }]);
+    }
+}
@@ -1,178 +1,431 @@
-use crate::parser::{AstNode, Expression};
-use crate::symbol_table::{SymbolTable, Symbol, SymbolType, Scope};
-use crate::types::{Type, TYPE_DWORD, TYPE_USIZE}; // Örnek tipler
-
-pub struct SemanticAnalyzer {
-    pub symbol_table: SymbolTable,
-    current_scope: Scope,
-}
-
-impl SemanticAnalyzer {
-    pub fn new() -> Self {
-        SemanticAnalyzer {
-            symbol_table: SymbolTable::new(),
-            current_scope: Scope::Global,
-        }
-    }
-
-    pub fn analyze(&mut self, ast: &AstNode) {
-        match ast {
-            AstNode::Program(statements) => {
-                for statement in statements {
-                    self.analyze_statement(statement);
-                }
-            }
-            _ => panic!("Beklenmeyen AST kök düğümü"),
-        }
-    }
-
-    fn analyze_statement(&mut self, node: &AstNode) {
-        match node {
-            AstNode::Label { name } => {
-                self.declare_symbol(name, SymbolType::Label, None);
-            }
-            AstNode::Assignment { variable, value } => {
-                self.resolve_variable(variable);
-                self.analyze_expression(value, None); // İsteğe bağlı beklenen tip
-                // Burada tip kontrolü yapılabilir (basitlik için atlandı)
-            }
-            AstNode::JumpStatement { target } => {
-                self.resolve_label(target);
-            }
-            AstNode::AllocateMemory { size, handle } => {
-                self.analyze_expression(size, Some(&TYPE_USIZE));
-                self.declare_symbol(handle, SymbolType::Handle, Some(&TYPE_USIZE)); // Handle usize olmalı
-            }
-            AstNode::ReleaseMemory { handle } => {
-                self.analyze_expression(handle, Some(&TYPE_USIZE)); // Handle usize olmalı
-            }
-            AstNode::SpawnTask { function, priority } => {
-                self.resolve_procedure(function);
-                if let Some(prio) = priority {
-                    self.analyze_expression(prio, Some(&TYPE_DWORD)); // Öncelik dword olabilir
-                }
-            }
-            AstNode::ExitTask { code } => {
-                if let Some(c) = code {
-                    self.analyze_expression(c, Some(&TYPE_DWORD)); // Çıkış kodu dword olabilir
-                }
-            }
-            AstNode::SleepTask { duration } => {
-                self.analyze_expression(duration, Some(&TYPE_DWORD)); // Süre dword olabilir
-            }
-            AstNode::YieldTask => {}
-            AstNode::AcquireResource { name, handle } => {
-                self.analyze_expression(name, Some(&Type::string())); // Kaynak adı string olmalı
-                self.declare_symbol(handle, SymbolType::Handle, Some(&TYPE_USIZE)); // Handle usize olmalı
-            }
-            AstNode::ControlResource { handle, command } => {
-                self.analyze_expression(handle, Some(&TYPE_USIZE)); // Handle usize olmalı
-                self.analyze_expression(command, Some(&TYPE_DWORD)); // Komut dword olabilir
-            }
-            AstNode::SendMessage { handle, message } => {
-                self.analyze_expression(handle, Some(&TYPE_USIZE)); // Handle usize olmalı
-                self.analyze_expression(message, None); // Mesajın tipi şu an belirsiz
-            }
-            AstNode::ReceiveMessage { handle, buffer } => {
-                self.analyze_expression(handle, Some(&TYPE_USIZE)); // Handle usize olmalı
-                self.resolve_variable(buffer); // Buffer bir değişken olmalı
-                // Burada buffer'ın yeterli boyutta olup olmadığı kontrol edilebilir
-            }
-            AstNode::GetTaskId { target } => {
-                self.declare_symbol(target, SymbolType::TaskId, Some(&TYPE_USIZE)); // TaskId usize olmalı
-            }
-            AstNode::GetCoreId { target } => {
-                self.declare_symbol(target, SymbolType::TaskId, Some(&TYPE_USIZE)); // CoreId usize olmalı
-            }
-            AstNode::GetTotalCores { target } => {
-                self.declare_symbol(target, SymbolType::TaskId, Some(&TYPE_USIZE)); // TotalCores usize olmalı
-            }
-            AstNode::Instruction { opcode: _, operands } => {
-                for operand in operands {
-                    self.analyze_expression(operand, None); // Operandların tipleri bağlama göre kontrol edilebilir
-                }
-            }
-            _ => {}
-        }
-    }
-
-    fn analyze_expression(&self, expression: &Expression, expected_type: Option<&Type>) {
-        match expression {
-            Expression::Identifier(name) => {
-                self.resolve_variable(name);
-                if let Some(expected) = expected_type {
-                    if let Some(symbol) = self.symbol_table.lookup(name) {
-                        if let SymbolType::Variable(Some(actual)) = &symbol.symbol_type {
-                            if actual != expected {
-                                panic!("Tip uyuşmazlığı: '{}' bekleniyordu, '{}' bulundu", expected, actual);
-                            }
-                        }
-                    }
-                }
-            }
-            Expression::Number(_) => {
-                if let Some(expected) = expected_type {
-                    if !matches!(expected.base, crate::types::BaseType::Integer { .. } | crate::types::BaseType::Pointer) {
-                        panic!("Tip uyuşmazlığı: Sayı beklenmiyordu");
-                    }
-                }
-            }
-            Expression::Flag(_) => {} // Flag'lerin tipleri bağlama göre kontrol edilebilir
-            Expression::StringLiteral(_) => {
-                if let Some(expected) = expected_type {
-                    if !matches!(expected.base, crate::types::BaseType::String) {
-                        panic!("Tip uyuşmazlığı: String beklenmiyordu");
-                    }
-                }
-            }
-            Expression::Handle(_) => {
-                if let Some(expected) = expected_type {
-                    if !matches!(expected.base, crate::types::BaseType::Integer { size: crate::types::IntegerSize::QWord, .. }) {
-                        panic!("Tip uyuşmazlığı: Handle beklenmiyordu");
-                    }
-                }
-            }
-            Expression::TaskId(_) => {
-                if let Some(expected) = expected_type {
-                    if !matches!(expected.base, crate::types::BaseType::Integer { size: crate::types::IntegerSize::QWord, .. }) {
-                        panic!("Tip uyuşmazlığı: TaskId beklenmiyordu");
-                    }
-                }
-            }
-        }
-    }
-
-    fn declare_symbol(&mut self, name: &str, symbol_type: SymbolType, var_type: Option<&Type>) {
-        let symbol = Symbol {
-            name: name.clone(),
-            symbol_type: match symbol_type {
-                SymbolType::Variable(_) => SymbolType::Variable(var_type.cloned()),
-                other => other,
-            },
-            scope: self.current_scope.clone(),
-        };
-        if self.symbol_table.lookup_in_scope(name, &self.current_scope).is_some() {
-            panic!("Sembol '{}' zaten bu kapsamda tanımlı", name);
-        }
-        self.symbol_table.insert(symbol);
-    }
-
-    fn resolve_variable(&self, name: &str) {
-        if self.symbol_table.lookup(name).filter(|s| matches!(s.symbol_type, SymbolType::Variable(_))).is_none() {
-            panic!("Tanımsız değişken '{}'", name);
-        }
-        // İstenirse değişkenin türü ve diğer özellikleri burada kontrol edilebilir
-    }
-
-    fn resolve_label(&self, name: &str) {
-        if self.symbol_table.lookup(name).filter(|s| s.symbol_type == SymbolType::Label).is_none() {
-            panic!("Tanımsız etiket '{}'", name);
-        }
-    }
-
-    fn resolve_procedure(&self, name: &str) {
-        if self.symbol_table.lookup(name).filter(|s| s.symbol_type == SymbolType::Procedure).is_none() {
-            panic!("Tanımsız prosedür '{}'", name);
-        }
-    }
+use crate::diagnostic_code::DiagnosticCode;
+use crate::error_reporter::{Diagnostic, ErrorReporter, Lint};
+use crate::extern_symbols::ExternSymbolTable;
+use crate::instructions::{self, OperandSlot};
+use crate::lexer::SourceLocation;
+use crate::messages::{msg, Arg, Message};
+use crate::parser::{AstNode, DataKind, Expression, Statement};
+use crate::symbol_table::{SymbolTable, Symbol, SymbolType, Scope};
+use crate::target::Target;
+use crate::types::{Type, TYPE_BYTE, TYPE_DWORD, TYPE_USIZE, TYPE_WORD}; // Örnek tipler
+
+pub struct SemanticAnalyzer {
+    pub symbol_table: SymbolTable,
+    pub extern_symbol_table: ExternSymbolTable,
+    pub control_flow_trace: Vec<String>, // analyze_control_flow'un gözlemleri
+    pub(crate) current_scope: Scope,
+    pub(crate) current_location: Option<SourceLocation>, // Analiz edilen deyimin ya da işlenenin konumu
+    operand_locations: Vec<SourceLocation>, // Analiz edilen deyimdeki ifadelerin konumları
+    pub(crate) target: Target, // Register sayısı gibi hedef makine özellikleri
+    error_reporter: ErrorReporter,
+}
+
+impl Default for SemanticAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SemanticAnalyzer {
+    pub fn new() -> Self {
+        Self::with_target(Target::default())
+    }
+
+    pub fn with_target(target: Target) -> Self {
+        SemanticAnalyzer {
+            symbol_table: SymbolTable::new(),
+            extern_symbol_table: ExternSymbolTable::new(),
+            control_flow_trace: Vec::new(),
+            current_scope: Scope::Global,
+            current_location: None,
+            operand_locations: Vec::new(),
+            target,
+            error_reporter: ErrorReporter::new(),
+        }
+    }
+
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        self.error_reporter.diagnostics()
+    }
+
+    pub fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        self.error_reporter.take_diagnostics()
+    }
+
+    // Deyimin analizine başlar; tanılamalar deyimin konumunda raporlanır
+    pub(crate) fn enter_statement(&mut self, statement: &Statement) {
+        self.current_location = Some(statement.location);
+        self.operand_locations.clone_from(&statement.operands);
+    }
+
+    // Deyimin index'inci ifadesini analiz eder; bu sırada raporlanan tanılamalar
+    // bütün satırı değil yalnızca o işleneni gösterir
+    pub(crate) fn in_operand(&mut self, index: usize, analyze: impl FnOnce(&mut Self)) {
+        let statement_location = self.current_location;
+        if let Some(location) = self.operand_locations.get(index) {
+            self.current_location = Some(*location);
+        }
+        analyze(self);
+        self.current_location = statement_location;
+    }
+
+    // Analiz edilen deyimin (ya da işlenenin) konumunda bir semantik hata raporlar
+    pub(crate) fn report_error(&mut self, code: DiagnosticCode, message: Message) {
+        self.error_reporter.report_error_at(code, message, self.current_location);
+    }
+
+    pub(crate) fn report_error_with_help(&mut self, code: DiagnosticCode, message: Message, help: Message) {
+        self.error_reporter.report(Diagnostic::error(code, message, self.current_location).with_help(help));
+    }
+
+    pub(crate) fn report_warning(&mut self, lint: Lint, message: Message) {
+        self.error_reporter.report_warning(lint, message, self.current_location);
+    }
+
+    pub(crate) fn report(&mut self, diagnostic: Diagnostic) {
+        self.error_reporter.report(diagnostic);
+    }
+
+    // Prosedür etiketleri ("PROCEDURE_AD:") sonraki tanımlar için yerel kapsamı başlatır
+    pub(crate) fn track_scope(&mut self, node: &AstNode) {
+        if let AstNode::Label { name } = node {
+            if let Some(procedure) = name.strip_prefix("PROCEDURE_") {
+                self.current_scope = Scope::Local(procedure.to_string());
+            }
+        }
+    }
+
+    // Yeniden tanımlama hatası; ilk tanımın konumu ikincil etiket olarak gösterilir
+    pub(crate) fn report_redefinition(&mut self, message: Message, previous: Option<SourceLocation>) {
+        let mut diagnostic = Diagnostic::error(DiagnosticCode::Redefinition, message, self.current_location).with_help(msg!("help.rename-redefinition"));
+        if let Some(previous) = previous {
+            diagnostic = diagnostic.with_label(previous, msg!("label.first-definition"));
+        }
+        self.error_reporter.report(diagnostic);
+    }
+
+    pub fn analyze(&mut self, ast: &AstNode) {
+        self.collect_extern_symbols(ast); // EXTERN sembolleri kullanımdan önce bilinmeli
+        self.current_scope = Scope::Global;
+        match ast {
+            AstNode::Program(statements) => {
+                self.collect_definitions(statements);
+                for statement in statements {
+                    self.enter_statement(statement);
+                    self.analyze_statement(&statement.node);
+                    self.track_scope(&statement.node);
+                }
+            }
+            _ => self.report_error(DiagnosticCode::Internal, msg!("B0901")),
+        }
+    }
+
+    // Etiketler ve prosedürler tanımlarından önce de kullanılabilir (ileri atlamalar,
+    // CALL); bu yüzden kullanımlar çözümlenmeden önce hepsi tanımlanır
+    fn collect_definitions(&mut self, statements: &[Statement]) {
+        for statement in statements {
+            self.current_location = Some(statement.location);
+            if let AstNode::Label { name } = &statement.node {
+                self.declare_symbol(name, SymbolType::Label, None);
+                if let Some(procedure) = name.strip_prefix("PROCEDURE_") {
+                    self.declare_procedure(procedure);
+                }
+            }
+            self.track_scope(&statement.node);
+        }
+        self.current_scope = Scope::Global;
+    }
+
+    fn analyze_statement(&mut self, node: &AstNode) {
+        match node {
+            AstNode::Label { .. } => {} // collect_definitions tarafından tanımlandı
+            AstNode::Assignment { variable, value } => {
+                self.resolve_variable(variable);
+                self.in_operand(0, |this| {
+                    this.analyze_expression(value, None); // İsteğe bağlı beklenen tip
+                    this.check_assignment_order(variable, value);
+                });
+                // Burada tip kontrolü yapılabilir (basitlik için atlandı)
+            }
+            AstNode::JumpStatement { target } => {
+                self.resolve_label(target);
+            }
+            AstNode::AllocateMemory { size, handle } => {
+                self.in_operand(0, |this| this.analyze_expression(size, Some(&TYPE_USIZE)));
+                self.declare_symbol(handle, SymbolType::Handle, Some(&TYPE_USIZE)); // Handle usize olmalı
+            }
+            AstNode::ReleaseMemory { handle } => self.in_operand(0, |this| this.analyze_handle(handle)),
+            AstNode::SpawnTask { function, priority } => {
+                self.resolve_procedure(function);
+                if let Some(prio) = priority {
+                    self.in_operand(0, |this| this.analyze_expression(prio, Some(&TYPE_DWORD))); // Öncelik dword olabilir
+                }
+            }
+            AstNode::ExitTask { code: Some(c) } => {
+                self.in_operand(0, |this| this.analyze_expression(c, Some(&TYPE_DWORD))); // Çıkış kodu dword olabilir
+            }
+            AstNode::SleepTask { duration } => {
+                self.in_operand(0, |this| this.analyze_expression(duration, Some(&TYPE_DWORD))); // Süre dword olabilir
+            }
+            AstNode::YieldTask => {}
+            AstNode::AcquireResource { name, handle } => {
+                self.in_operand(0, |this| this.analyze_expression(name, Some(&Type::string()))); // Kaynak adı string olmalı
+                self.declare_symbol(handle, SymbolType::Handle, Some(&TYPE_USIZE)); // Handle usize olmalı
+            }
+            AstNode::ControlResource { handle, command } => {
+                self.in_operand(0, |this| this.analyze_handle(handle));
+                self.in_operand(1, |this| {
+                    if let Expression::StringLiteral(_) = command {
+                        // Sahne64 kaynak kontrolü (ioctl benzeri) yalnızca sayısal komut kodu alır
+                        this.report_error(DiagnosticCode::ApiCall, msg!("B0307", "CTRL", Arg::Key("B0307.ctrl-command")));
+                    } else {
+                        this.analyze_expression(command, Some(&TYPE_DWORD)); // Komut dword olabilir
+                    }
+                });
+            }
+            AstNode::SendMessage { handle, message } => {
+                self.in_operand(0, |this| this.analyze_handle(handle));
+                self.in_operand(1, |this| this.analyze_expression(message, None)); // Mesajın tipi şu an belirsiz
+            }
+            AstNode::ReceiveMessage { .. } => {} // Handle ve buffer analyze_io'da denetlenir
+            AstNode::GetTaskId { target } => {
+                self.declare_symbol(target, SymbolType::TaskId, Some(&TYPE_USIZE)); // TaskId usize olmalı
+            }
+            AstNode::GetCoreId { target } => {
+                self.declare_symbol(target, SymbolType::TaskId, Some(&TYPE_USIZE)); // CoreId usize olmalı
+            }
+            AstNode::GetTotalCores { target } => {
+                self.declare_symbol(target, SymbolType::TaskId, Some(&TYPE_USIZE)); // TotalCores usize olmalı
+            }
+            AstNode::Instruction { opcode, .. } if opcode == "GLOBAL" || opcode == "EXTERN" => {
+                // collect_extern_symbols tarafından işlendi
+            }
+            AstNode::Instruction { opcode, operands } if opcode == "VAR" => self.declare_var(operands),
+            AstNode::Section { .. } => {}
+            AstNode::DataDirective { kind, values } => {
+                let width = data_width(*kind);
+                for (index, value) in values.iter().enumerate() {
+                    self.in_operand(index, |this| match (value, &width) {
+                        // Sayılar direktifin öğe genişliğine sığmalı (.byte 300 kesilir)
+                        (Expression::Number(_), Some(width)) => this.analyze_expression(value, Some(width)),
+                        _ => this.analyze_constant_operand(value),
+                    });
+                }
+            }
+            AstNode::Constant { name, value } => {
+                self.in_operand(0, |this| this.analyze_constant_operand(value));
+                self.declare_symbol(name, SymbolType::Constant, None);
+            }
+            AstNode::Instruction { opcode, operands } => {
+                for (index, operand) in operands.iter().enumerate() {
+                    self.in_operand(index, |this| match (instructions::slot(opcode, index), operand) {
+                        (Some(OperandSlot::Label), Expression::Identifier(target)) => this.resolve_label(target),
+                        (Some(OperandSlot::Procedure), _) => {} // analyze_functions çözümler
+                        (Some(_), Expression::Identifier(name)) => this.resolve_operand(name),
+                        _ => this.analyze_expression(operand, None), // Operandların tipleri bağlama göre kontrol edilebilir
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Atama hedefin üzerinde soldan sağa hesaplanır; hedef ilk işlenen dışında
+    // kullanılırsa ("A = 1 - A") önceki adımlar onun değerini çoktan değiştirmiş olur
+    fn check_assignment_order(&mut self, variable: &str, value: &Expression) {
+        let mut operand = value;
+        while let Expression::Binary { left, right, .. } = operand {
+            if matches!(right.as_ref(), Expression::Identifier(name) if name == variable) {
+                self.report_error(DiagnosticCode::InvalidOperand, msg!("B0306.assignment", variable));
+                return;
+            }
+            operand = left;
+        }
+    }
+
+    pub(crate) fn analyze_expression(&mut self, expression: &Expression, expected_type: Option<&Type>) {
+        match expression {
+            Expression::Identifier(name) => {
+                self.resolve_variable(name);
+                if let Some(expected) = expected_type {
+                    if let Some(actual) = self.get_variable_type(name).filter(|actual| *actual != expected).cloned() {
+                        self.report_error(DiagnosticCode::TypeMismatch, msg!("B0301", expected, actual));
+                    }
+                }
+            }
+            Expression::Number(value) => {
+                if let Some(expected) = expected_type {
+                    if !matches!(expected.base, crate::types::BaseType::Integer { .. } | crate::types::BaseType::Pointer) {
+                        self.report_error(DiagnosticCode::TypeMismatch, msg!("B0301.number"));
+                    } else if !expected.fits(*value) {
+                        self.report_warning(Lint::TruncatingImmediate, msg!("B0603", value, expected));
+                    }
+                }
+            }
+            Expression::Flag(_) => {} // Flag'lerin tipleri bağlama göre kontrol edilebilir
+            Expression::StringLiteral(_) => {
+                if let Some(expected) = expected_type {
+                    if !matches!(expected.base, crate::types::BaseType::String) {
+                        self.report_error(DiagnosticCode::TypeMismatch, msg!("B0301.string"));
+                    }
+                }
+            }
+            Expression::Handle(_) => {
+                if let Some(expected) = expected_type {
+                    if !matches!(expected.base, crate::types::BaseType::Integer { size: crate::types::IntegerSize::QWord, .. }) {
+                        self.report_error(DiagnosticCode::TypeMismatch, msg!("B0301.handle"));
+                    }
+                }
+            }
+            Expression::TaskId(_) => {
+                if let Some(expected) = expected_type {
+                    if !matches!(expected.base, crate::types::BaseType::Integer { size: crate::types::IntegerSize::QWord, .. }) {
+                        self.report_error(DiagnosticCode::TypeMismatch, msg!("B0301.task-id"));
+                    }
+                }
+            }
+            Expression::Register(index) => {
+                if !self.target.has_register(*index) {
+                    let last = self.target.register_count.saturating_sub(1);
+                    self.report_error(DiagnosticCode::InvalidRegister, msg!("B0308", &self.target.name, index, last));
+                }
+            }
+            Expression::AddressOf(name) => self.resolve_address(name),
+            Expression::LocationCounter => {} // Adres; tipi bağlama göre belirlenir
+            Expression::Memory(address) => self.analyze_constant_operand(address), // Register, etiket veya adres aritmetiği
+            Expression::Binary { left, right, .. } => {
+                self.analyze_constant_operand(left);
+                self.analyze_constant_operand(right);
+            }
+        }
+    }
+
+    // Veri direktifleri ve sabit ifadelerinde adlar değişken olmak zorunda değildir;
+    // etiketler ve sabitler de adres aritmetiğinde kullanılabilir
+    pub(crate) fn analyze_constant_operand(&mut self, expression: &Expression) {
+        match expression {
+            Expression::Identifier(name) => self.resolve_value(name),
+            other => self.analyze_expression(other, None),
+        }
+    }
+
+    pub(crate) fn declare_symbol(&mut self, name: &str, symbol_type: SymbolType, var_type: Option<&Type>) {
+        let symbol = Symbol {
+            name: name.to_string(),
+            symbol_type: match symbol_type {
+                SymbolType::Variable(_) => SymbolType::Variable(var_type.cloned()),
+                other => other,
+            },
+            scope: self.current_scope.clone(),
+            location: self.current_location,
+        };
+        if let Some(previous) = self.symbol_table.lookup_in_scope(name, &self.current_scope) {
+            let previous = previous.location;
+            self.report_redefinition(msg!("B0204", name), previous);
+            return;
+        }
+        self.warn_if_shadowing(name);
+        self.warn_if_confusable(name);
+        self.symbol_table.insert(symbol);
+    }
+
+    // Başka bir kapsamdaki aynı adlı sembolü gölgeleyen tanımlar için uyarı
+    pub(crate) fn warn_if_shadowing(&mut self, name: &str) {
+        let shadowed = match self.symbol_table.lookup(name) {
+            Some(symbol) if symbol.scope != self.current_scope => symbol.location,
+            _ => return,
+        };
+        let mut diagnostic = Diagnostic::warning(Lint::ShadowedSymbol, msg!("B0602", name), self.current_location);
+        if let Some(shadowed) = shadowed {
+            diagnostic = diagnostic.with_label(shadowed, msg!("label.shadowed-definition"));
+        }
+        self.report(diagnostic);
+    }
+
+    // Var olan bir adla ekranda aynı görünen yeni adlar için uyarı
+    pub(crate) fn warn_if_confusable(&mut self, name: &str) {
+        let (other, location) = match self.symbol_table.confusable_with(name) {
+            Some(symbol) => (symbol.name.clone(), symbol.location),
+            None => return,
+        };
+        let mut diagnostic = Diagnostic::warning(Lint::ConfusableIdentifier, msg!("B0606", name, &other), self.current_location);
+        if let Some(location) = location {
+            diagnostic = diagnostic.with_label(location, msg!("label.confusable-definition"));
+        }
+        self.report(diagnostic);
+    }
+
+    pub(crate) fn resolve_variable(&mut self, name: &str) {
+        if self.symbol_table.lookup(name).filter(|s| matches!(s.symbol_type, SymbolType::Variable(_) | SymbolType::Constant)).is_none() {
+            self.report_error_with_help(DiagnosticCode::UndefinedVariable, msg!("B0201", name), msg!("help.undefined-variable"));
+        }
+        // İstenirse değişkenin türü ve diğer özellikleri burada kontrol edilebilir
+    }
+
+    // Komut işlenenindeki yalın ad: değişken ve sabitlerin yanında etiketler ve dış
+    // semboller de kullanılabilir ("LOAD R1, MSG"); bulunamazsa tanımsız değişkendir
+    pub(crate) fn resolve_operand(&mut self, name: &str) {
+        let label = self.symbol_table.lookup(name).is_some_and(|s| matches!(s.symbol_type, SymbolType::Label | SymbolType::Procedure));
+        if !label && !self.is_external(name) {
+            self.resolve_variable(name);
+        }
+    }
+
+    // Değeri veya adresi olan herhangi bir sembol
+    pub(crate) fn resolve_value(&mut self, name: &str) {
+        let defined = self.symbol_table.lookup(name).is_some_and(|s| {
+            matches!(s.symbol_type, SymbolType::Variable(_) | SymbolType::Constant | SymbolType::Label | SymbolType::Procedure)
+        });
+        if !defined && !self.is_external(name) {
+            self.report_error_with_help(DiagnosticCode::UndefinedLabel, msg!("B0202", name), msg!("help.undefined-label", name));
+        }
+    }
+
+    // $ad: adresi alınabilen semboller etiketler, prosedürler ve statik değişkenlerdir
+    pub(crate) fn resolve_address(&mut self, name: &str) {
+        let addressable = self.symbol_table.lookup(name).is_some_and(|s| {
+            matches!(s.symbol_type, SymbolType::Label | SymbolType::Procedure | SymbolType::Variable(_))
+        });
+        if !addressable && !self.is_external(name) {
+            self.report_error_with_help(DiagnosticCode::UndefinedLabel, msg!("B0202", name), msg!("help.undefined-label", name));
+        }
+    }
+
+    // RELEASE, CTRL, SEND ve RECV'nin handle işleneni: ad ise ALLOCATE, ACQUIRE veya
+    // GET_* ile edinilmiş bir handle ya da handle tutan bir değişken olmalı
+    pub(crate) fn analyze_handle(&mut self, handle: &Expression) {
+        match handle {
+            Expression::Identifier(name) => {
+                let defined = self.symbol_table.lookup(name).is_some_and(|s| {
+                    matches!(s.symbol_type, SymbolType::Handle | SymbolType::TaskId | SymbolType::Variable(_) | SymbolType::Constant)
+                });
+                if !defined {
+                    self.report_error(DiagnosticCode::UndefinedHandle, msg!("B0403", name));
+                }
+            }
+            other => self.analyze_expression(other, Some(&TYPE_USIZE)), // Handle usize olmalı
+        }
+    }
+
+    pub(crate) fn resolve_label(&mut self, name: &str) {
+        if self.symbol_table.lookup(name).filter(|s| s.symbol_type == SymbolType::Label).is_none() && !self.is_external(name) {
+            self.report_error_with_help(DiagnosticCode::UndefinedLabel, msg!("B0202", name), msg!("help.undefined-label", name));
+        }
+    }
+
+    pub(crate) fn resolve_procedure(&mut self, name: &str) {
+        if self.symbol_table.lookup(name).filter(|s| s.symbol_type == SymbolType::Procedure).is_none() && !self.is_external(name) {
+            self.report_error_with_help(DiagnosticCode::UndefinedProcedure, msg!("B0203", name), msg!("help.undefined-procedure", name));
+        }
+    }
+}
+
+// Veri direktifinin öğe tipi; .qword 64 bitin tamamını, .string ise metni alır
+fn data_width(kind: DataKind) -> Option<Type> {
+    match kind {
+        DataKind::Byte => Some(TYPE_BYTE),
+        DataKind::Word => Some(TYPE_WORD),
+        DataKind::DWord => Some(TYPE_DWORD),
+        DataKind::QWord | DataKind::String => None,
+    }
 }
\ No newline at end of file
@@ -1,53 +1,115 @@
-use std::collections::HashMap;
-use crate::types::Type; // Tipleri kullanacağız
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum SymbolType {
-    Variable(Option<Type>), // Değişkenler isteğe bağlı bir tipe sahip olabilir
-    Label,
-    Procedure,
-    Macro,
-    External,
-    Global,
-    Handle,
-    TaskId,
-    ResourceId,
-}
-
-#[derive(Debug, Clone)]
-pub struct Symbol {
-    pub name: String,
-    pub symbol_type: SymbolType,
-    pub scope: Scope, // Basit kapsam yönetimi
-    // Diğer özellikler (adres vb.) eklenebilir
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum Scope {
-    Global,
-    Local(String), // Prosedür adı
-}
-
-pub struct SymbolTable {
-    symbols: HashMap<String, Symbol>,
-}
-
-impl SymbolTable {
-    pub fn new() -> Self {
-        SymbolTable {
-            symbols: HashMap::new(),
-        }
-    }
-
-    pub fn insert(&mut self, symbol: Symbol) {
-        self.symbols.insert(symbol.name.clone(), symbol);
-    }
-
-    pub fn lookup(&self, name: &str) -> Option<&Symbol> {
-        self.symbols.get(name)
-    }
-
-    pub fn lookup_in_scope(&self, name: &str, scope: &Scope) -> Option<&Symbol> {
-        self.symbols.get(name).filter(|s| &s.scope == scope)
-    }
+use std::collections::HashMap;
+use crate::lexer::SourceLocation;
+use crate::types::Type; // Tipleri kullanacağız
+use crate::unicode;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SymbolType {
+    Variable(Option<Type>), // Değişkenler isteğe bağlı bir tipe sahip olabilir
+    Label,
+    Constant, // Veri bölümünde "ad = ifade" ile tanımlanan değer
+    Procedure,
+    Macro,
+    External,
+    Global,
+    Handle,
+    TaskId,
+    ResourceId,
+}
+
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    pub name: String,
+    pub symbol_type: SymbolType,
+    pub scope: Scope, // Basit kapsam yönetimi
+    pub location: Option<SourceLocation>, // Tanımlandığı deyimin konumu
+    // Diğer özellikler (adres vb.) eklenebilir
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Scope {
+    Global,
+    Local(String), // Prosedür adı
+}
+
+// Adlar NFC biçiminde saklanır ve aranır; aynı görünen iki yazım aynı sembole çıkar
+#[derive(Debug, Clone)]
+pub struct SymbolTable {
+    symbols: HashMap<String, Symbol>,
+    skeletons: HashMap<String, String>, // Görsel iskelet -> bu iskeletle ilk tanımlanan ad
+}
+
+impl Default for SymbolTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SymbolTable {
+    pub fn new() -> Self {
+        SymbolTable {
+            symbols: HashMap::new(),
+            skeletons: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, mut symbol: Symbol) {
+        symbol.name = unicode::nfc(&symbol.name).into_owned();
+        self.skeletons.entry(unicode::skeleton(&symbol.name)).or_insert_with(|| symbol.name.clone());
+        self.symbols.insert(symbol.name.clone(), symbol);
+    }
+
+    pub fn lookup(&self, name: &str) -> Option<&Symbol> {
+        self.symbols.get(unicode::nfc(name).as_ref())
+    }
+
+    // Adı farklı yazılan ama ekranda aynı görünen sembol (ör. "sayı" için "sayi")
+    pub fn confusable_with(&self, name: &str) -> Option<&Symbol> {
+        let name = unicode::nfc(name);
+        self.skeletons
+            .get(&unicode::skeleton(&name))
+            .filter(|other| other.as_str() != name)
+            .and_then(|other| self.symbols.get(other))
+    }
+
+    // Tüm semboller, ada göre sıralı
+    pub fn symbols(&self) -> Vec<&Symbol> {
+        let mut symbols: Vec<&Symbol> = self.symbols.values().collect();
+        symbols.sort_by(|a, b| a.name.cmp(&b.name));
+        symbols
+    }
+
+    // Koşulu sağlayan sembollerin adlarını sıralı döndürür
+    pub fn names_where(&self, predicate: impl Fn(&Symbol) -> bool) -> Vec<String> {
+        let mut names: Vec<String> = self.symbols.values().filter(|s| predicate(s)).map(|s| s.name.clone()).collect();
+        names.sort();
+        names
+    }
+
+    pub fn lookup_in_scope(&self, name: &str, scope: &Scope) -> Option<&Symbol> {
+        self.lookup(name).filter(|s| &s.scope == scope)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(name: &str) -> Symbol {
+        Symbol { name: name.to_string(), symbol_type: SymbolType::Label, scope: Scope::Global, location: None }
+    }
+
+    #[test]
+    fn test_names_are_normalized_and_confusables_found() {
+        let mut table = SymbolTable::new();
+        table.insert(label("s\u{0327}is\u{0327}e"));
+        assert_eq!(table.lookup("şişe").map(|s| s.name.as_str()), Some("şişe"));
+        assert!(table.lookup_in_scope("s\u{0327}is\u{0327}e", &Scope::Global).is_some());
+        assert!(table.confusable_with("şişe").is_none());
+
+        table.insert(label("sayı"));
+        assert_eq!(table.confusable_with("sayi").map(|s| s.name.as_str()), Some("sayı"));
+        assert!(table.confusable_with("sayı").is_none());
+        assert!(table.confusable_with("sayaç").is_none());
+    }
 }
\ No newline at end of file
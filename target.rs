@@ -0,0 +1,33 @@
+// Derlemenin hedeflediği Sahne64 sanal makinesinin özellikleri. Bessambly
+// donanıma özgü değildir; hedef yalnızca makinenin sunduğu kaynakları tanımlar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Target {
+    pub name: String,
+    pub register_count: usize, // Genel amaçlı registerlar: R0..R(n-1)
+}
+
+impl Default for Target {
+    fn default() -> Self {
+        Self::sahne64()
+    }
+}
+
+impl Target {
+    // Sahne64 çağrı kuralı R0 (syscall numarası ve dönüş değeri), R1-R5
+    // (argümanlar) ve iki serbest register kullanır
+    pub fn sahne64() -> Self {
+        Target {
+            name: "sahne64".to_string(),
+            register_count: 8,
+        }
+    }
+
+    pub fn with_register_count(mut self, register_count: usize) -> Self {
+        self.register_count = register_count;
+        self
+    }
+
+    pub fn has_register(&self, index: usize) -> bool {
+        index < self.register_count
+    }
+}
@@ -1,97 +1,106 @@
-use crate::parser::{AstNode, Expression};
-use crate::semantic_analyzer::SemanticAnalyzer;
-use crate::symbol_table::{Symbol, SymbolType, Scope};
-use crate::types::{Type, TYPE_DWORD, TYPE_USIZE, TYPE_STRING, TYPE_HANDLE, TYPE_TASK_ID}; // İlgili tipleri kullanacağız
-
-impl SemanticAnalyzer {
-    pub fn analyze_variables(&mut self, ast: &AstNode) {
-        match ast {
-            AstNode::Program(statements) => {
-                for statement in statements {
-                    self.analyze_variable_statement(statement);
-                }
-            }
-            _ => {}
-        }
-    }
-
-    fn analyze_variable_statement(&mut self, node: &AstNode) {
-        match node {
-            // Değişken tanımlama (örneğin "VAR my_var DWORD") - AST'ye eklenmesi gerekebilir
-            AstNode::Instruction { opcode, operands } if opcode == "VAR" => {
-                if operands.len() == 2 {
-                    if let (Expression::Identifier(var_name), Expression::Identifier(type_name)) = (&operands[0], &operands[1]) {
-                        let var_type = self.resolve_type(type_name);
-                        self.declare_variable(var_name, var_type);
-                    } else {
-                        panic!("VAR komutu geçerli bir değişken adı ve tip adı almalı");
-                    }
-                } else {
-                    panic!("VAR komutu iki operand almalı (ad ve tip)");
-                }
-            }
-            AstNode::Assignment { variable, value } => {
-                self.resolve_variable(variable);
-                self.analyze_expression(value, self.get_variable_type(variable));
-                // Tip uyumluluğu kontrolü analyze_expression içinde yapılıyor
-            }
-            AstNode::Instruction { opcode: _, operands } => {
-                for operand in operands {
-                    if let Expression::Identifier(var_name) = operand {
-                        self.resolve_variable(var_name);
-                    }
-                }
-            }
-            AstNode::AllocateMemory { handle, .. } |
-            AstNode::AcquireResource { handle, .. } |
-            AstNode::GetTaskId { target: handle } |
-            AstNode::GetCoreId { target: handle } |
-            AstNode::GetTotalCores { target: handle } => {
-                // Bu yapılar zaten semantik analizde handle/task_id olarak tanımlanıyor
-            }
-            AstNode::ReceiveMessage { buffer, .. } => {
-                self.resolve_variable(buffer); // Buffer bir değişken olmalı
-                // Burada buffer'ın yeterli boyutta olup olmadığı kontrol edilebilir
-            }
-            _ => {}
-        }
-    }
-
-    fn declare_variable(&mut self, name: &str, var_type: Type) {
-        let symbol = Symbol {
-            name: name.to_string(),
-            symbol_type: SymbolType::Variable(Some(var_type)),
-            scope: self.current_scope.clone(),
-            // İleride adres bilgileri eklenebilir
-        };
-        if self.symbol_table.lookup_in_scope(name, &self.current_scope).is_some() {
-            panic!("Değişken '{}' zaten bu kapsamda tanımlı", name);
-        }
-        self.symbol_table.insert(symbol);
-    }
-
-    fn get_variable_type(&self, name: &str) -> Option<&Type> {
-        if let Some(symbol) = self.symbol_table.lookup(name) {
-            if let SymbolType::Variable(var_type) = &symbol.symbol_type {
-                return var_type.as_ref();
-            }
-        }
-        None
-    }
-
-    // Basit tip çözümleyici
-    fn resolve_type(&self, type_name: &str) -> Type {
-        match type_name {
-            "DWORD" => TYPE_DWORD.clone(),
-            "BYTE" => crate::types::TYPE_BYTE.clone(),
-            "WORD" => crate::types::TYPE_WORD.clone(),
-            "QWORD" => crate::types::TYPE_QWORD.clone(),
-            "PTR" => crate::types::TYPE_POINTER.clone(),
-            "STRING" => TYPE_STRING.clone(),
-            "HANDLE" => TYPE_HANDLE.clone(),
-            "TASK_ID" => TYPE_TASK_ID.clone(),
-            "USIZE" => TYPE_USIZE.clone(),
-            _ => panic!("Bilinmeyen tip '{}'", type_name),
-        }
-    }
+use crate::diagnostic_code::DiagnosticCode;
+use crate::instructions::{self, OperandSlot};
+use crate::messages::msg;
+use crate::parser::{AstNode, Expression};
+use crate::semantic_analyzer::SemanticAnalyzer;
+use crate::symbol_table::{Scope, Symbol, SymbolType};
+use crate::types::{Type, TYPE_DWORD, TYPE_USIZE, TYPE_STRING, TYPE_HANDLE, TYPE_TASK_ID}; // İlgili tipleri kullanacağız
+
+impl SemanticAnalyzer {
+    pub fn analyze_variables(&mut self, ast: &AstNode) {
+        self.current_scope = Scope::Global;
+        if let AstNode::Program(statements) = ast {
+            for statement in statements {
+                self.enter_statement(statement);
+                self.analyze_variable_statement(&statement.node);
+                self.track_scope(&statement.node);
+            }
+        }
+    }
+
+    fn analyze_variable_statement(&mut self, node: &AstNode) {
+        match node {
+            // VAR bildirimleri kullanımlarından önce analyze içinde tanımlandı
+            AstNode::Instruction { opcode, .. } if opcode == "VAR" => {}
+            AstNode::Assignment { variable, value } => {
+                self.resolve_variable(variable);
+                let expected_type = self.get_variable_type(variable).cloned();
+                self.in_operand(0, |this| this.analyze_expression(value, expected_type.as_ref()));
+                // Tip uyumluluğu kontrolü analyze_expression içinde yapılıyor
+            }
+            AstNode::Instruction { opcode, .. } if opcode == "GLOBAL" || opcode == "EXTERN" => {}
+            AstNode::Instruction { opcode, operands } => {
+                for (index, operand) in operands.iter().enumerate() {
+                    let names_code = matches!(instructions::slot(opcode, index), Some(OperandSlot::Label | OperandSlot::Procedure));
+                    if let Expression::Identifier(var_name) = operand {
+                        if !names_code {
+                            self.in_operand(index, |this| this.resolve_operand(var_name));
+                        }
+                    }
+                }
+            }
+            AstNode::AllocateMemory { .. } |
+            AstNode::AcquireResource { .. } |
+            AstNode::GetTaskId { .. } |
+            AstNode::GetCoreId { .. } |
+            AstNode::GetTotalCores { .. } => {
+                // Bu yapılar zaten semantik analizde handle/task_id olarak tanımlanıyor
+            }
+            _ => {}
+        }
+    }
+
+    // "VAR sayac DWORD" bildirimi; parser iki adı da doğruladı, burada tip çözümlenir
+    pub(crate) fn declare_var(&mut self, operands: &[Expression]) {
+        if let [Expression::Identifier(var_name), Expression::Identifier(type_name)] = operands {
+            match self.resolve_type(type_name) {
+                Some(var_type) => self.declare_variable(var_name, var_type),
+                None => self.report_error(DiagnosticCode::UnknownType, msg!("B0206", type_name)),
+            }
+        }
+    }
+
+    fn declare_variable(&mut self, name: &str, var_type: Type) {
+        let symbol = Symbol {
+            name: name.to_string(),
+            symbol_type: SymbolType::Variable(Some(var_type)),
+            scope: self.current_scope.clone(),
+            location: self.current_location,
+            // İleride adres bilgileri eklenebilir
+        };
+        if let Some(previous) = self.symbol_table.lookup_in_scope(name, &self.current_scope) {
+            let previous = previous.location;
+            self.report_redefinition(msg!("B0204.variable", name), previous);
+            return;
+        }
+        self.warn_if_shadowing(name);
+        self.warn_if_confusable(name);
+        self.symbol_table.insert(symbol);
+    }
+
+    pub(crate) fn get_variable_type(&self, name: &str) -> Option<&Type> {
+        if let Some(symbol) = self.symbol_table.lookup(name) {
+            if let SymbolType::Variable(var_type) = &symbol.symbol_type {
+                return var_type.as_ref();
+            }
+        }
+        None
+    }
+
+    // Basit tip çözümleyici; bilinmeyen tip adları için None döner
+    pub(crate) fn resolve_type(&self, type_name: &str) -> Option<Type> {
+        let resolved = match type_name {
+            "DWORD" => TYPE_DWORD.clone(),
+            "BYTE" => crate::types::TYPE_BYTE.clone(),
+            "WORD" => crate::types::TYPE_WORD.clone(),
+            "QWORD" => crate::types::TYPE_QWORD.clone(),
+            "PTR" => crate::types::TYPE_POINTER.clone(),
+            "STRING" => TYPE_STRING.clone(),
+            "HANDLE" => TYPE_HANDLE.clone(),
+            "TASK_ID" => TYPE_TASK_ID.clone(),
+            "USIZE" => TYPE_USIZE.clone(),
+            _ => return None,
+        };
+        Some(resolved)
+    }
 }
\ No newline at end of file